@@ -0,0 +1,215 @@
+//! Benchmark command implementation
+//!
+//! Runs declarative JSON workload files against the running dev/server
+//! stack and reports per-command latency stats, mirroring an xtask-bench
+//! style workflow: small reusable workload files plus an optional
+//! push-to-server reporting step so CI can track regressions over time.
+
+use crate::{Cli, Terminal};
+use crate::utils::Messenger;
+use ethereum_boilerplate_utils::{Config, Result, UtilsError};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+#[derive(Debug, Deserialize)]
+struct Workload {
+    name: String,
+    target: String,
+    #[serde(default)]
+    setup: Vec<Value>,
+    commands: Vec<WorkloadCommand>,
+    #[serde(default = "default_repeat")]
+    repeat: u32,
+}
+
+fn default_repeat() -> u32 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkloadCommand {
+    id: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CommandStats {
+    id: String,
+    samples: u32,
+    min_ms: f64,
+    median_ms: f64,
+    p95_ms: f64,
+    max_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    workload: String,
+    target: String,
+    git_commit: String,
+    timestamp: String,
+    commands: Vec<CommandStats>,
+}
+
+pub async fn run_bench(config: Config, workload_paths: Vec<String>, upload: bool) -> Result<()> {
+    let ui = Cli::parse();
+    ui.banner();
+
+    if workload_paths.is_empty() {
+        ui.fail("No workload files given. Pass one or more --workload paths.");
+        return Err(UtilsError::config_error("No workload files provided"));
+    }
+
+    for path in &workload_paths {
+        let workload = load_workload(path)?;
+        ui.info(&format!("Running workload '{}' against {}", workload.name, workload.target));
+
+        let report = run_workload(&ui, &workload).await?;
+        print_report(&ui, &report);
+
+        if upload {
+            upload_report(&config, &report).await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn load_workload(path: &str) -> Result<Workload> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| UtilsError::config_error(format!("Failed to read workload '{}': {}", path, e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| UtilsError::config_error(format!("Invalid workload file '{}': {}", path, e)))
+}
+
+async fn run_workload(ui: &Cli, workload: &Workload) -> Result<BenchReport> {
+    let client = reqwest::Client::new();
+
+    // Setup calls run once, un-timed, to seed any required state.
+    for setup_call in &workload.setup {
+        send(&client, &workload.target, setup_call).await.ok();
+    }
+
+    let total_calls = workload.commands.len() as u64 * workload.repeat as u64;
+
+    let mut samples: Vec<(String, Vec<f64>)> = workload
+        .commands
+        .iter()
+        .map(|c| (c.id.clone(), Vec::with_capacity(workload.repeat as usize)))
+        .collect();
+
+    let bar = crate::create_progress_bar(total_calls);
+    for _ in 0..workload.repeat {
+        for command in &workload.commands {
+            let body = serde_json::json!({ "id": command.id, "method": command.method, "params": command.params });
+            let start = Instant::now();
+            let _ = send(&client, &workload.target, &body).await;
+            let elapsed = start.elapsed();
+
+            if let Some((_, values)) = samples.iter_mut().find(|(id, _)| id == &command.id) {
+                values.push(duration_ms(elapsed));
+            }
+            bar.inc(1);
+        }
+    }
+    bar.finish_with_message("Benchmark run complete");
+
+    let commands = samples
+        .into_iter()
+        .map(|(id, mut values)| {
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            CommandStats {
+                id,
+                samples: values.len() as u32,
+                min_ms: values.first().copied().unwrap_or(0.0),
+                median_ms: percentile(&values, 0.50),
+                p95_ms: percentile(&values, 0.95),
+                max_ms: values.last().copied().unwrap_or(0.0),
+            }
+        })
+        .collect();
+
+    ui.success("Workload finished");
+
+    Ok(BenchReport {
+        workload: workload.name.clone(),
+        target: workload.target.clone(),
+        git_commit: current_git_commit().await,
+        timestamp: current_timestamp(),
+        commands,
+    })
+}
+
+async fn send(client: &reqwest::Client, target: &str, body: &Value) -> Result<Value> {
+    let response = client
+        .post(target)
+        .json(body)
+        .send()
+        .await
+        .map_err(UtilsError::network)?;
+    response
+        .json::<Value>()
+        .await
+        .map_err(UtilsError::network)
+}
+
+fn duration_ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() as f64 - 1.0) * pct).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+fn print_report(ui: &Cli, report: &BenchReport) {
+    ui.success(&format!("Results for '{}' ({})", report.workload, report.target));
+    println!("{:<20} {:>8} {:>10} {:>10} {:>10} {:>10}", "command", "samples", "min(ms)", "median(ms)", "p95(ms)", "max(ms)");
+    for stats in &report.commands {
+        println!(
+            "{:<20} {:>8} {:>10.2} {:>10.2} {:>10.2} {:>10.2}",
+            stats.id, stats.samples, stats.min_ms, stats.median_ms, stats.p95_ms, stats.max_ms
+        );
+    }
+}
+
+async fn upload_report(config: &Config, report: &BenchReport) -> Result<()> {
+    let Some(url) = &config.bench_results_url else {
+        return Err(UtilsError::config_error(
+            "bench_results_url is not configured; set it in config.json or BENCH_RESULTS_URL",
+        ));
+    };
+
+    let client = reqwest::Client::new();
+    client
+        .post(url)
+        .json(report)
+        .send()
+        .await
+        .map_err(UtilsError::network)?;
+
+    Ok(())
+}
+
+async fn current_git_commit() -> String {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().await;
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).trim().to_string(),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn current_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
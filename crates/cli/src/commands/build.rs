@@ -1,27 +1,78 @@
 //! Build command implementation
 
-use crate::{Cli, Terminal, create_spinner};
+use crate::{Cli, Terminal, create_spinner, create_progress_bar};
 use ethereum_boilerplate_utils::{Config, Result, UtilsError};
 use crate::utils::{CliUi, Messenger};
 use tokio::process::Command;
 use std::path::Path;
 use colored::Colorize;
 use clap::Parser;
+use sha2::Digest;
+
+/// Linker binary to inject via `CARGO_TARGET_<TRIPLE>_LINKER` for each
+/// supported cross-compilation target, so `cargo build --target` can
+/// produce a binary for an ARM edge host without a `.cargo/config.toml`.
+fn cross_linker(target: &str) -> Option<&'static str> {
+    match target {
+        "armv7-unknown-linux-gnueabihf" => Some("arm-linux-gnueabihf-gcc"),
+        "arm-unknown-linux-gnueabihf" => Some("arm-linux-gnueabihf-gcc"),
+        "aarch64-unknown-linux-gnu" => Some("aarch64-linux-gnu-gcc"),
+        _ => None,
+    }
+}
+
+/// The full target matrix `build --release-matrix` cross-compiles the
+/// server binary for, matching the architectures actually deployed: edge
+/// ARM boxes, x86_64 Linux servers, and Windows developer workstations.
+const RELEASE_TARGET_MATRIX: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "armv7-unknown-linux-gnueabihf",
+    "arm-unknown-linux-gnueabihf",
+    "aarch64-unknown-linux-gnu",
+    "x86_64-pc-windows-msvc",
+];
+
+/// Cross toolchain binary required to build each target, or `None` when the
+/// host's own native toolchain already handles it. Checked with
+/// `is_installed` before the build so a missing toolchain fails fast with
+/// an actionable message instead of a cryptic linker error mid-build.
+fn required_tool(target: &str) -> Option<&'static str> {
+    match target {
+        "armv7-unknown-linux-gnueabihf" => Some("arm-linux-gnueabihf-gcc"),
+        "arm-unknown-linux-gnueabihf" => Some("arm-linux-gnueabihf-gcc"),
+        "aarch64-unknown-linux-gnu" => Some("aarch64-linux-gnu-gcc"),
+        // MSVC has no GNU-style cross linker; `cargo-xwin` supplies one by
+        // fetching the Windows SDK/CRT and driving `lld-link` itself.
+        "x86_64-pc-windows-msvc" => Some("cargo-xwin"),
+        _ => None,
+    }
+}
+
+fn release_binary_filename(target: &str) -> &'static str {
+    if target.ends_with("windows-msvc") { "server.exe" } else { "server" }
+}
+
+/// `CARGO_TARGET_<TRIPLE>_LINKER` env var name for `target` (dashes and
+/// dots become underscores, per Cargo's naming convention).
+fn linker_env_var(target: &str) -> String {
+    format!("CARGO_TARGET_{}_LINKER", target.to_uppercase().replace(['-', '.'], "_"))
+}
 
 /// Сборка всего проекта
-#[allow(dead_code)]
 pub async fn build_project(
     _config: Config,
     release: bool,
     skip_frontend: bool,
     skip_backend: bool,
+    target: Option<String>,
+    package: Option<String>,
 ) -> Result<()> {
     let ui = Cli::parse();
     ui.banner();
 
     // 1. Сборка Бэкенда
     if !skip_backend {
-        build_backend(&ui, release).await?;
+        build_backend(&ui, release, target.as_deref()).await?;
     }
 
     // 2. Сборка Фронтенда
@@ -29,15 +80,21 @@ pub async fn build_project(
         build_frontend(&ui, release).await?;
     }
 
-    // 3. Итоговый отчет
-    show_artifacts(&ui, release, skip_backend, skip_frontend);
+    // 3. Упаковка (опционально)
+    let mut packages = Vec::new();
+    if package.as_deref() == Some("deb") {
+        let deb_path = package_deb(&ui, release, target.as_deref()).await?;
+        packages.push(deb_path);
+    }
+
+    // 4. Итоговый отчет
+    show_artifacts(&ui, release, skip_backend, skip_frontend, target.as_deref(), &packages);
 
     Ok(())
 }
 
-/// Сборка Rust бэкенда
-#[allow(dead_code)]
-async fn build_backend(ui: &Cli, release: bool) -> Result<()> {
+/// Сборка Rust бэкенда, опционально для другой целевой платформы
+async fn build_backend(ui: &Cli, release: bool, target: Option<&str>) -> Result<()> {
     let pb = create_spinner("Сборка бэкенда (Rust server)...");
 
     if !Path::new("crates/server").exists() {
@@ -49,11 +106,20 @@ async fn build_backend(ui: &Cli, release: bool) -> Result<()> {
     if release {
         args.push("--release");
     }
+    if let Some(triple) = target {
+        args.push("--target");
+        args.push(triple);
+    }
 
-    let status = Command::new("cargo")
-        .args(&args)
-        .status()
-        .await?;
+    let mut command = Command::new("cargo");
+    command.args(&args);
+    if let Some(triple) = target {
+        if let Some(linker) = cross_linker(triple) {
+            command.env(linker_env_var(triple), linker);
+        }
+    }
+
+    let status = command.status().await?;
 
     pb.finish_and_clear();
 
@@ -62,12 +128,127 @@ async fn build_backend(ui: &Cli, release: bool) -> Result<()> {
         return Err(UtilsError::internal("Backend build failed"));
     }
 
+    if let Some(triple) = target {
+        strip_binary(&backend_binary_path(release, Some(triple))).await?;
+    }
+
     ui.success("Бэкенд успешно собран.");
     Ok(())
 }
 
+/// Path to the built server binary for the given release mode/target.
+fn backend_binary_path(release: bool, target: Option<&str>) -> String {
+    let mode = if release { "release" } else { "debug" };
+    match target {
+        Some(triple) => format!("target/{}/{}/server", triple, mode),
+        None => format!("target/{}/server", mode),
+    }
+}
+
+/// Strips debug symbols from a cross-compiled binary so it's deployable to
+/// a space-constrained edge host.
+async fn strip_binary(path: &str) -> Result<()> {
+    if !Path::new(path).exists() {
+        return Err(UtilsError::internal(format!("Expected built binary at '{}' but it's missing", path)));
+    }
+
+    let status = Command::new("strip").arg(path).status().await?;
+    if !status.success() {
+        return Err(UtilsError::internal(format!("Stripping '{}' failed", path)));
+    }
+
+    Ok(())
+}
+
+/// Assembles a Debian package from the already built server binary
+/// (stripped only when it was cross-compiled with `--target`): stages it under `deb/usr/bin/`, writes a generated `control`
+/// file with the version read from `crates/server/Cargo.toml`, invokes
+/// `dpkg-deb -b`, and writes an `.md5` checksum next to the resulting
+/// `.deb`. Returns the path to the produced `.deb`.
+async fn package_deb(ui: &Cli, release: bool, target: Option<&str>) -> Result<String> {
+    let pb = create_spinner("Сборка .deb пакета...");
+
+    let binary_path = backend_binary_path(release, target);
+    if !Path::new(&binary_path).exists() {
+        pb.finish_and_clear();
+        return Err(UtilsError::internal(format!("Expected built binary at '{}' but it's missing", binary_path)));
+    }
+
+    let version = read_crate_version("crates/server/Cargo.toml").unwrap_or_else(|| "0.0.0".to_string());
+    let arch = deb_architecture(target);
+
+    let stage_dir = Path::new("deb");
+    let bin_dir = stage_dir.join("usr/bin");
+    tokio::fs::create_dir_all(&bin_dir).await?;
+    tokio::fs::copy(&binary_path, bin_dir.join("server")).await?;
+
+    let control_dir = stage_dir.join("DEBIAN");
+    tokio::fs::create_dir_all(&control_dir).await?;
+    let control = format!(
+        "Package: ethereum-boilerplate-server\nVersion: {}\nArchitecture: {}\nMaintainer: Ethereum Boilerplate\nDescription: Ethereum Boilerplate backend server\n",
+        version, arch
+    );
+    tokio::fs::write(control_dir.join("control"), control).await?;
+
+    let deb_path = format!("target/ethereum-boilerplate-server_{}_{}.deb", version, arch);
+    let status = Command::new("dpkg-deb")
+        .args(["-b", "deb", &deb_path])
+        .status()
+        .await?;
+
+    pb.finish_and_clear();
+
+    if !status.success() {
+        ui.fail("Сборка .deb пакета провалилась.");
+        return Err(UtilsError::internal("dpkg-deb build failed"));
+    }
+
+    write_md5_checksum(&deb_path).await?;
+
+    ui.success(".deb пакет собран.");
+    Ok(deb_path)
+}
+
+/// Reads the `version = "..."` field out of a crate's `[package]` section.
+/// Parsed by hand rather than pulling in a TOML crate, since this is the
+/// only place the CLI needs to read a manifest.
+fn read_crate_version(manifest_path: &str) -> Option<String> {
+    let content = std::fs::read_to_string(manifest_path).ok()?;
+    content.lines().find_map(|line| {
+        let line = line.trim();
+        line.strip_prefix("version")
+            .and_then(|rest| rest.trim_start().strip_prefix('='))
+            .map(|rest| rest.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Maps a Rust target triple to the Debian architecture name used in
+/// package filenames and the `control` file.
+fn deb_architecture(target: Option<&str>) -> &'static str {
+    match target {
+        Some("armv7-unknown-linux-gnueabihf") => "armhf",
+        Some("aarch64-unknown-linux-gnu") => "arm64",
+        _ => "amd64",
+    }
+}
+
+async fn write_md5_checksum(path: &str) -> Result<()> {
+    let bytes = tokio::fs::read(path).await?;
+    let hex = format!("{:x}", md5::compute(&bytes));
+
+    tokio::fs::write(format!("{}.md5", path), format!("{}  {}\n", hex, path)).await?;
+    Ok(())
+}
+
+async fn write_sha256_checksum(path: &str) -> Result<()> {
+    let bytes = tokio::fs::read(path).await?;
+    let hex = format!("{:x}", sha2::Sha256::digest(&bytes));
+
+    tokio::fs::write(format!("{}.sha256", path), format!("{}  {}\n", hex, path)).await?;
+    Ok(())
+}
+
 /// Сборка Frontend (Leptos + WASM)
-#[allow(dead_code)]
 async fn build_frontend(ui: &Cli, release: bool) -> Result<()> {
     let pb = create_spinner("Подготовка Trunk и сборка WASM...");
 
@@ -105,7 +286,6 @@ async fn build_frontend(ui: &Cli, release: bool) -> Result<()> {
 }
 
 /// Проверка и установка Trunk если нужно
-#[allow(dead_code)]
 async fn check_or_install_trunk(_ui: &Cli) -> Result<bool> {
     let ui = CliUi;
     let has_trunk = Command::new("trunk").arg("--version").output().await.is_ok();
@@ -124,7 +304,6 @@ async fn check_or_install_trunk(_ui: &Cli) -> Result<bool> {
 }
 
 /// Проверка наличия инструмента
-#[allow(dead_code)]
 async fn is_installed(tool: &str) -> bool {
     Command::new(tool)
         .arg("--version")
@@ -134,19 +313,124 @@ async fn is_installed(tool: &str) -> bool {
         .unwrap_or(false)
 }
 
-fn show_artifacts(ui: &Cli, release: bool, skip_backend: bool, skip_frontend: bool) {
+fn show_artifacts(
+    ui: &Cli,
+    release: bool,
+    skip_backend: bool,
+    skip_frontend: bool,
+    target: Option<&str>,
+    packages: &[String],
+) {
     println!("\n{}", "📦 Сборка завершена. Артефакты:".bold().underline());
 
-    let mode = if release { "release" } else { "debug" };
-
     if !skip_backend {
-        let backend_binary = format!("target/{}/server", mode);
-        println!("  {:<12} {}", "Бэкенд:".cyan(), backend_binary.green());
+        let backend_binary = backend_binary_path(release, target);
+        let label = match target {
+            Some(triple) => format!("Бэкенд ({}):", triple),
+            None => "Бэкенд:".to_string(),
+        };
+        println!("  {:<24} {}", label.cyan(), backend_binary.green());
     }
 
     if !skip_frontend {
-        println!("  {:<12} {}", "Фронтенд:".cyan(), "crates/frontend/dist/".green());
+        println!("  {:<24} {}", "Фронтенд:".cyan(), "crates/frontend/dist/".green());
+    }
+
+    for package in packages {
+        println!("  {:<24} {}", "Пакет:".cyan(), package.green());
+        println!("  {:<24} {}", "Контрольная сумма:".cyan(), format!("{}.md5", package).green());
     }
 
     ui.success("Проект готов к деплою!");
+}
+
+/// Drives a reproducible multi-target release: for each entry in
+/// `RELEASE_TARGET_MATRIX` (or `only` when the caller restricts it),
+/// verifies the target's cross toolchain is installed, runs
+/// `cargo build -p server --release --target`, strips the result where
+/// stripping applies, and writes an `.md5`/`.sha256` checksum file next to
+/// the binary. Reports progress with a single bar across the whole matrix
+/// instead of a spinner per target, and keeps going past a target whose
+/// toolchain is missing so one absent cross compiler doesn't block the
+/// rest of the matrix.
+pub async fn build_release_matrix(only: Option<Vec<String>>) -> Result<()> {
+    let ui = Cli::parse();
+    ui.banner();
+
+    let targets: Vec<String> = only.unwrap_or_else(|| {
+        RELEASE_TARGET_MATRIX.iter().map(|t| t.to_string()).collect()
+    });
+
+    let progress = create_progress_bar(targets.len() as u64);
+    let mut artifacts = Vec::new();
+
+    for target in &targets {
+        progress.set_message(format!("Building {}...", target));
+
+        if let Some(tool) = required_tool(target) {
+            if !is_installed(tool).await {
+                ui.fail(&format!(
+                    "Missing cross toolchain '{}' for target '{}'; install it and re-run. Skipping this target.",
+                    tool, target
+                ));
+                progress.inc(1);
+                continue;
+            }
+        }
+
+        let mut command = Command::new("cargo");
+        if target.ends_with("windows-msvc") {
+            // `cargo-xwin` drives the MSVC link from a Linux host; a plain
+            // `cargo build --target` has no MSVC linker to call here.
+            command.args(["xwin", "build", "--release", "-p", "server", "--target", target]);
+        } else {
+            command.args(["build", "--release", "-p", "server", "--target", target]);
+            if let Some(linker) = cross_linker(target) {
+                command.env(linker_env_var(target), linker);
+            }
+        }
+
+        let status = command.status().await?;
+        if !status.success() {
+            ui.fail(&format!("Build failed for target '{}'.", target));
+            progress.inc(1);
+            continue;
+        }
+
+        let binary_path = format!("target/{}/release/{}", target, release_binary_filename(target));
+
+        if !target.ends_with("windows-msvc") {
+            if let Err(e) = strip_binary(&binary_path).await {
+                ui.fail(&format!("Stripping '{}' failed: {}", binary_path, e));
+            }
+        }
+
+        if let Err(e) = write_md5_checksum(&binary_path).await {
+            ui.fail(&format!("Checksumming (md5) '{}' failed: {}", binary_path, e));
+        }
+        if let Err(e) = write_sha256_checksum(&binary_path).await {
+            ui.fail(&format!("Checksumming (sha256) '{}' failed: {}", binary_path, e));
+        }
+
+        artifacts.push(binary_path);
+        progress.inc(1);
+    }
+
+    progress.finish_and_clear();
+
+    println!("\n{}", "📦 Release matrix complete. Artifacts:".bold().underline());
+    for artifact in &artifacts {
+        println!("  {:<48} {}.md5 {}.sha256", artifact.green(), artifact, artifact);
+    }
+
+    if artifacts.len() < targets.len() {
+        ui.warn(&format!(
+            "{} of {} targets failed or were skipped; see messages above.",
+            targets.len() - artifacts.len(),
+            targets.len()
+        ));
+    }
+
+    ui.success("Release matrix finished.");
+    Ok(())
 }
\ No newline at end of file
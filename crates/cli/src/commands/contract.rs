@@ -2,7 +2,7 @@
 
 use ethereum_boilerplate_utils::{
     print_banner, print_success, print_error, create_progress_bar,
-    Config, Result, validate_address, validate_amount, UtilsError
+    Config, Result, validate_address, validate_address_checksummed, validate_amount, UtilsError
 };
 use alloy::primitives::{Address, U256};
 use alloy::providers::{Provider, ProviderBuilder, RootProvider};
@@ -39,6 +39,10 @@ pub async fn interact_with_contract(
         Some(ContractAction::Approve) => approve_tokens(config, contract_address, amount, to).await,
         Some(ContractAction::MintNft) => mint_nft(config, contract_address, token_id).await,
         Some(ContractAction::TransferNft) => transfer_nft_via_cli(config, contract_address, token_id, to).await,
+        Some(ContractAction::BalanceOfBatch) | Some(ContractAction::TransferBatch) => {
+            print_error("ERC-1155 batch actions are not yet available via this command; use the server's /erc1155 route.");
+            Ok(())
+        }
         None => {
             let available_actions = ContractAction::all()
                 .iter()
@@ -64,12 +68,15 @@ async fn get_balance(config: Config, contract_address: Address) -> Result<()> {
 
 async fn mint_tokens(config: Config, contract_address: Address, amount: Option<String>) -> Result<()> {
     let amount_str = prompt_if_none(amount, "Enter amount to mint", Some(validate_amount))?;
+    let fees = fetch_eip1559_fees(&config).await?;
     run_interact_command(
         &config,
         &[
             "--address", &format!("{:#x}", contract_address),
             "--action", &ContractAction::Mint.to_string(),
             "--amount", &amount_str,
+            "--max-fee-per-gas", &fees.max_fee,
+            "--max-priority-fee-per-gas", &fees.max_priority_fee,
         ],
         "Minting tokens...",
         &format!("Successfully minted {} tokens!", amount_str),
@@ -79,7 +86,8 @@ async fn mint_tokens(config: Config, contract_address: Address, amount: Option<S
 async fn transfer_tokens(config: Config, contract_address: Address, amount: Option<String>, to: Option<String>) -> Result<()> {
     let amount_str = prompt_if_none(amount, "Enter amount to transfer", Some(validate_amount))?;
     let to_str = prompt_if_none(to, "Enter recipient address", None)?;
-    validate_address(&to_str)?;
+    validate_address_checksummed(&to_str)?;
+    let fees = fetch_eip1559_fees(&config).await?;
 
     run_interact_command(
         &config,
@@ -88,6 +96,8 @@ async fn transfer_tokens(config: Config, contract_address: Address, amount: Opti
             "--action", &ContractAction::Transfer.to_string(),
             "--amount", &amount_str,
             "--to", &to_str,
+            "--max-fee-per-gas", &fees.max_fee,
+            "--max-priority-fee-per-gas", &fees.max_priority_fee,
         ],
         "Executing transfer...",
         &format!("Successfully transferred {} to {}", amount_str, to_str),
@@ -97,7 +107,8 @@ async fn transfer_tokens(config: Config, contract_address: Address, amount: Opti
 async fn approve_tokens(config: Config, contract_address: Address, amount: Option<String>, to: Option<String>) -> Result<()> {
     let amount_str = prompt_if_none(amount, "Enter amount to approve", Some(validate_amount))?;
     let to_str = prompt_if_none(to, "Enter spender address", None)?;
-    validate_address(&to_str)?;
+    validate_address_checksummed(&to_str)?;
+    let fees = fetch_eip1559_fees(&config).await?;
 
     run_interact_command(
         &config,
@@ -106,12 +117,34 @@ async fn approve_tokens(config: Config, contract_address: Address, amount: Optio
             "--action", &ContractAction::Approve.to_string(),
             "--amount", &amount_str,
             "--to", &to_str,
+            "--max-fee-per-gas", &fees.max_fee,
+            "--max-priority-fee-per-gas", &fees.max_priority_fee,
         ],
         "Approving tokens...",
         &format!("Successfully approved {} for {}", amount_str, to_str),
     ).await
 }
 
+/// `maxFeePerGas`/`maxPriorityFeePerGas`, formatted as decimal wei strings
+/// for the `interact` bin's CLI flags.
+struct Eip1559Fees {
+    max_fee: String,
+    max_priority_fee: String,
+}
+
+/// Estimates EIP-1559 fees from `eth_feeHistory` so mint/transfer/approve
+/// build a typed transaction with real values instead of letting the
+/// downstream `interact` bin fall back to its own (likely stale) defaults.
+async fn fetch_eip1559_fees(config: &Config) -> Result<Eip1559Fees> {
+    let provider = ethereum_boilerplate_utils::network::create_provider(&config.network)?;
+    let estimate = ethereum_boilerplate_utils::network::estimate_eip1559_fees(&provider).await?;
+
+    Ok(Eip1559Fees {
+        max_fee: estimate.max_fee_per_gas.to_string(),
+        max_priority_fee: estimate.max_priority_fee_per_gas.to_string(),
+    })
+}
+
 async fn mint_nft(config: Config, contract_address: Address, token_id: Option<String>) -> Result<()> {
     let id_str = prompt_if_none(token_id, "Enter Token ID", None)?;
     run_interact_command(
@@ -130,7 +163,8 @@ async fn mint_nft(config: Config, contract_address: Address, token_id: Option<St
 async fn transfer_nft_via_cli(config: Config, contract_address: Address, token_id: Option<String>, to: Option<String>) -> Result<()> {
     let id_str = prompt_if_none(token_id, "Enter Token ID", None)?;
     let to_str = prompt_if_none(to, "Enter recipient address", None)?;
-    
+    validate_address_checksummed(&to_str)?;
+
     run_interact_command(
         &config,
         &[
@@ -1,12 +1,32 @@
 //! Deploy command implementation
 
-use crate::{Cli, Terminal, create_spinner};
+use crate::{Cli, Terminal, create_spinner, create_progress_bar};
 use crate::utils::Messenger;
 use ethereum_boilerplate_utils::{Config, Result, print_banner, print_error};
 use crate::commands::contract::prompt_if_none;
 use tokio::process::Command;
-use ethereum_boilerplate_shared::ContractTemplate;
+use ethereum_boilerplate_shared::{ContractTemplate, GasPriceStrategy};
+use ethereum_boilerplate_smart_contracts::middleware::{DeployMiddlewareStack, PriorityFeeStrategy};
+use ethereum_boilerplate_utils::network::{create_provider, get_chain_id};
+use ethereum_boilerplate_smart_contracts::plan::{AddressBook, DeployPlan, DeployedContract, SimulatedDeploy};
+use ethereum_boilerplate_smart_contracts::{ContractManager, DeploymentRecord, DeploySubprocessResult, DEFAULT_REGISTRY_PATH};
 use clap::Parser;
+use dialoguer::Confirm;
+
+/// Checks that the configured network's RPC endpoint actually answers
+/// before a plan's simulate/broadcast phases touch it, the same way
+/// `ensure_wasm_target`/`ensure_trunk_installed` validate the frontend
+/// toolchain before `run_frontend` proceeds.
+async fn ensure_rpc_reachable(config: &Config) -> Result<()> {
+    let provider = create_provider(&config.network)?;
+    get_chain_id(&provider).await.map_err(|e| {
+        ethereum_boilerplate_utils::UtilsError::config_error(format!(
+            "RPC endpoint '{}' is not reachable: {}",
+            config.network.rpc_url, e
+        ))
+    })?;
+    Ok(())
+}
 
 pub async fn deploy_contracts(
     config: Config,
@@ -14,6 +34,7 @@ pub async fn deploy_contracts(
     private_key: Option<String>,
     _network_opt: Option<String>, // Не используется пока
     yes: bool,
+    gas_strategy: String,
 ) -> Result<()> {
     let ui = Cli::parse();
     print_banner();
@@ -46,13 +67,15 @@ pub async fn deploy_contracts(
         }
     };
 
+    let strategy = GasPriceStrategy::parse(&gas_strategy).unwrap_or(GasPriceStrategy::Standard);
+
     // 3. Выполнение развертывания
     match contract_template {
         Some(ContractTemplate::Erc20) | Some(ContractTemplate::Erc721) => {
-            execute_deployment(&ui, &config, &pk, &contract_type).await?;
+            execute_deployment(&ui, &config, &pk, &contract_type, strategy).await?;
         },
         Some(ContractTemplate::Custom) => {
-            execute_deployment(&ui, &config, &pk, &contract_type).await?;
+            execute_deployment(&ui, &config, &pk, &contract_type, strategy).await?;
         },
         None => if !yes {
             ui.fail("Развертывание отменено пользователем.");
@@ -64,28 +87,315 @@ pub async fn deploy_contracts(
     Ok(())
 }
 
-async fn execute_deployment(ui: &Cli, config: &Config, pk: &str, contract_kind: &str) -> Result<()> {
+/// Resolves the nonce and EIP-1559 fee fields the deploy transaction should
+/// use, flowing signer -> nonce manager -> gas oracle -> provider, so batch
+/// deploys of several contracts in one run don't collide on the same nonce.
+async fn prepare_middleware_stack(
+    config: &Config,
+    pk: &str,
+    strategy: GasPriceStrategy,
+) -> Result<(u64, alloy::primitives::U256, alloy::primitives::U256)> {
+    let provider = create_provider(&config.network)?;
+    let priority_strategy = match strategy {
+        GasPriceStrategy::Slow => PriorityFeeStrategy::Percentile(10.0),
+        GasPriceStrategy::Standard => PriorityFeeStrategy::Percentile(50.0),
+        GasPriceStrategy::Fast => PriorityFeeStrategy::Percentile(75.0),
+        GasPriceStrategy::Urgent => PriorityFeeStrategy::Percentile(90.0),
+    };
+    let stack = DeployMiddlewareStack::new(provider, priority_strategy);
+
+    use alloy::signers::Signer;
+    let deployer = pk
+        .parse::<alloy::signers::local::PrivateKeySigner>()
+        .map(|signer| signer.address())
+        .map_err(|e| ethereum_boilerplate_utils::UtilsError::invalid_private_key(e.to_string()))?;
+
+    let (nonce, gas) = stack
+        .prepare(deployer)
+        .await
+        .map_err(ethereum_boilerplate_utils::UtilsError::Ethereum)?;
+
+    Ok((nonce, gas.max_fee_per_gas, gas.max_priority_fee_per_gas))
+}
+
+/// Runs a multi-contract deploy plan: a `--dry-run` simulate phase that
+/// predicts addresses/gas for every planned contract, followed by an
+/// interactively-confirmed `--broadcast` phase that actually submits them.
+/// Already-deployed contracts (per the on-disk address book) are skipped so
+/// a plan can be resumed after a partial run. Returns the contracts that
+/// ended up deployed (address + tx hash), the same shape the server's
+/// transaction history endpoint surfaces to the frontend's `Transaction`
+/// views.
+pub async fn run_deploy_plan(
+    config: Config,
+    plan_path: String,
+    private_key: Option<String>,
+    dry_run: bool,
+    broadcast: bool,
+    yes: bool,
+) -> Result<Vec<DeployedContract>> {
+    let ui = Cli::parse();
+    print_banner();
+
+    ensure_rpc_reachable(&config).await?;
+
+    let plan = DeployPlan::load(&plan_path)
+        .map_err(|e| ethereum_boilerplate_utils::UtilsError::config_error(format!("Invalid deploy plan: {}", e)))?;
+
+    let book_path = "deployments.json";
+    let mut book = AddressBook::load(book_path);
+
+    ui.success(&format!("Simulating plan '{}' on network '{}'", plan_path, plan.network));
+    let bar = create_progress_bar(plan.contracts.len() as u64);
+    let mut simulated = Vec::new();
+    for contract in &plan.contracts {
+        bar.set_message(format!("Simulating {}", contract.name));
+        if book.already_deployed(&plan.network, &contract.name) {
+            ui.success(&format!("- {} already deployed, skipping", contract.name));
+            bar.inc(1);
+            continue;
+        }
+
+        let predicted = ethereum_boilerplate_utils::crypto::generate_address_from_salt(&contract.name);
+        let estimate = SimulatedDeploy {
+            name: contract.name.clone(),
+            predicted_address: predicted,
+            estimated_gas: 1_500_000,
+        };
+        ui.success(&format!(
+            "- {} ({}): predicted address {:#x}, est. gas {}, calls: {:?}",
+            estimate.name, contract.template, estimate.predicted_address, estimate.estimated_gas, contract.post_deploy_calls,
+        ));
+        simulated.push(estimate);
+        bar.inc(1);
+    }
+    bar.finish_and_clear();
+
+    if dry_run || !broadcast {
+        ui.success("Dry-run complete. Re-run with --broadcast to submit these deployments.");
+        return Ok(Vec::new());
+    }
+
+    if simulated.is_empty() {
+        ui.success("Nothing left to deploy; plan already fully applied.");
+        return Ok(Vec::new());
+    }
+
+    if !yes {
+        let proceed = Confirm::new()
+            .with_prompt(format!("Broadcast {} deployment(s) to '{}'?", simulated.len(), plan.network))
+            .default(false)
+            .interact()
+            .unwrap_or(false);
+        if !proceed {
+            ui.fail("Broadcast cancelled by user.");
+            return Ok(Vec::new());
+        }
+    }
+
+    let pk = match private_key {
+        Some(key) => key,
+        None => {
+            ui.fail("Private key is required to broadcast a deploy plan");
+            return Ok(Vec::new());
+        }
+    };
+
+    let bar = create_progress_bar(plan.contracts.len() as u64);
+    let mut deployed = Vec::new();
+    for contract in &plan.contracts {
+        bar.set_message(format!("Deploying {}", contract.name));
+        if book.already_deployed(&plan.network, &contract.name) {
+            bar.inc(1);
+            continue;
+        }
+
+        let strategy = GasPriceStrategy::Standard;
+        let Some(result) = execute_deployment(&ui, &config, &pk, &contract.template, strategy).await? else {
+            ui.fail(&format!(
+                "{} was not recorded in the address book (deployment failed, or no deploy \
+                 subprocess here reported its real address -- see the warning above); the \
+                 next run will retry it.",
+                contract.name
+            ));
+            bar.inc(1);
+            continue;
+        };
+
+        let record = DeployedContract {
+            address: result.address,
+            transaction_hash: result.transaction_hash,
+            block_number: result.block_number,
+        };
+        book.record(&plan.network, &contract.name, record.clone());
+        book.save(book_path)
+            .map_err(ethereum_boilerplate_utils::UtilsError::internal)?;
+        deployed.push(record);
+        bar.inc(1);
+    }
+    bar.finish_and_clear();
+
+    ui.success(&format!("Address book updated at {}", book_path));
+    Ok(deployed)
+}
+
+/// Runs the deploy subprocess for `contract_kind` and, on success, records it
+/// in the contract registry -- but only with the real address the
+/// subprocess reports via [`DeploySubprocessResult::read`]. If the
+/// subprocess didn't write a result file, nothing is recorded: a guessed
+/// address would be worse than no entry at all, since every reader of the
+/// registry treats a present entry as "really deployed here". Returns the
+/// subprocess's reported result, if any, so callers like
+/// [`run_deploy_plan`] can persist it to their own address book too.
+///
+/// As of this tree, that result is *always* `None`: see
+/// [`ethereum_boilerplate_smart_contracts::DEPLOY_RESULT_FILE`]'s docs for
+/// why no subprocess here ever writes one. Automatic registry recording is
+/// not a delivered feature yet -- only the (currently unreachable) safety
+/// net that keeps a future writer from needing CLI-side changes.
+async fn execute_deployment(
+    ui: &Cli,
+    config: &Config,
+    pk: &str,
+    contract_kind: &str,
+    gas_strategy: GasPriceStrategy,
+) -> Result<Option<DeploySubprocessResult>> {
     let pb = create_spinner(format!("Работа с {}", contract_kind.to_uppercase()).as_str());
-    
-    // Шаг 1: Компиляция и развертывание через внутренний скрипт
-    // Мы объединяем компиляцию и деплой в один вызов для скорости
-    pb.set_message(format!("Компиляция и деплой {}...", contract_kind));
-    
-    let status = Command::new("cargo")
-        .args(&["run", "--bin", "deploy", "--", "--contract", contract_kind, "--deploy"])
-        .env("PRIVATE_KEY", pk)
-        .env("NETWORK", &config.network.name)
-        .current_dir("crates/smart-contracts")
-        .status()
-        .await?;
-    
-    pb.finish_and_clear();
-    
-    if status.success() {
-        ui.success(&format!("Contract {} deployed successfully!", contract_kind));
+
+    // Шаг 1: Подготовка nonce/gas через middleware-стек (signer -> nonce manager -> gas oracle)
+    pb.set_message(format!("Подбираем nonce и газ ({} strategy)...", gas_strategy));
+    let (nonce, max_fee, priority_fee) = match prepare_middleware_stack(config, pk, gas_strategy).await {
+        Ok(values) => values,
+        Err(e) => {
+            pb.finish_and_clear();
+            ui.fail(&format!("Не удалось подготовить middleware-стек: {}", e));
+            return Ok(None);
+        }
+    };
+    let max_fee = if let Some(policy) = &config.network.gas_policy {
+        let (chosen, decision) = policy.enforce(
+            ethereum_boilerplate_shared::network::DEPLOYMENT_GAS_LIMIT,
+            max_fee.to::<u128>(),
+        );
+        match decision {
+            ethereum_boilerplate_utils::config::GasPolicyDecision::CeilingExceeded => {
+                pb.finish_and_clear();
+                ui.fail(&format!(
+                    "Projected fee exceeds the configured ceiling for network '{}'; adjust the gas policy or strategy to proceed.",
+                    config.network.name
+                ));
+                return Ok(None);
+            }
+            ethereum_boilerplate_utils::config::GasPolicyDecision::Fixed => {
+                pb.set_message(format!("Gas policy: using fixed gas price {} Wei for '{}'", chosen, config.network.name));
+                alloy::primitives::U256::from(chosen)
+            }
+            ethereum_boilerplate_utils::config::GasPolicyDecision::WithinCeiling => {
+                pb.set_message(format!("Gas policy: projected fee within ceiling for '{}'", config.network.name));
+                max_fee
+            }
+            ethereum_boilerplate_utils::config::GasPolicyDecision::Unconstrained => max_fee,
+        }
+    } else {
+        max_fee
+    };
+
+    pb.set_message(format!(
+        "nonce={} max_fee_per_gas={} max_priority_fee_per_gas={}",
+        nonce, max_fee, priority_fee
+    ));
+
+    // Шаг 2: Компиляция и развертывание
+    let crate_dir = if ContractTemplate::parse(contract_kind).map(|t| t.is_stylus()).unwrap_or(false) {
+        format!("contracts/{}", contract_kind.to_lowercase())
+    } else {
+        "crates/smart-contracts".to_string()
+    };
+
+    let status = if crate_dir.starts_with("contracts/") {
+        pb.set_message(format!("cargo stylus deploy {}...", contract_kind));
+        Command::new("cargo")
+            .args(&["stylus", "deploy", "--private-key", pk, "--endpoint"])
+            .arg(&config.network.rpc_url)
+            .current_dir(&crate_dir)
+            .status()
+            .await?
     } else {
+        // Мы объединяем компиляцию и деплой в один вызов для скорости
+        pb.set_message(format!("Компиляция и деплой {}...", contract_kind));
+        Command::new("cargo")
+            .args(&["run", "--bin", "deploy", "--", "--contract", contract_kind, "--deploy"])
+            .env("PRIVATE_KEY", pk)
+            .env("NETWORK", &config.network.name)
+            .env("NONCE", nonce.to_string())
+            .env("MAX_FEE_PER_GAS", max_fee.to_string())
+            .env("MAX_PRIORITY_FEE_PER_GAS", priority_fee.to_string())
+            .current_dir(&crate_dir)
+            .status()
+            .await?
+    };
+
+    pb.finish_and_clear();
+
+    if !status.success() {
         ui.fail("Contract deployment failed");
+        return Ok(None);
+    }
+
+    ui.success(&format!("Contract {} deployed successfully!", contract_kind));
+
+    let deployed = DeploySubprocessResult::read(&crate_dir);
+    let Some(deployed) = deployed else {
+        ui.fail(&format!(
+            "Contract {} deployed, but automatic registry recording isn't wired up on this \
+             tree yet: no deploy subprocess here writes '{}' with the real address, so this \
+             path never has one to read. Note the real address from the deploy output yourself \
+             and add it to {} by hand for now.",
+            contract_kind,
+            ethereum_boilerplate_smart_contracts::DEPLOY_RESULT_FILE,
+            DEFAULT_REGISTRY_PATH,
+        ));
+        return Ok(None);
+    };
+
+    let mut registry = ContractManager::load(DEFAULT_REGISTRY_PATH);
+    let deployer = pk
+        .parse::<alloy::signers::local::PrivateKeySigner>()
+        .ok()
+        .map(|signer| { use alloy::signers::Signer; signer.address() });
+    registry.record_deployment(
+        &config.network.name,
+        config.network.chain_id,
+        contract_kind,
+        DeploymentRecord {
+            address: deployed.address,
+            transaction_hash: deployed.transaction_hash,
+            block_number: deployed.block_number,
+            abi_path: Some(format!("crates/smart-contracts/abi/{}.json", contract_kind)),
+            deployer,
+        },
+    );
+    if let Err(e) = registry.save(DEFAULT_REGISTRY_PATH) {
+        ui.fail(&format!("Deployed, but failed to update contract registry: {}", e));
+    }
+
+    Ok(Some(deployed))
+}
+
+/// Prints every contract recorded in the persistent deployment registry,
+/// optionally filtered to a single network.
+pub async fn list_contracts(network: Option<String>) -> Result<()> {
+    print_banner();
+    let registry = ContractManager::load(DEFAULT_REGISTRY_PATH);
+    let rows = registry.list_contracts(network.as_deref());
+
+    if rows.is_empty() {
+        println!("No contracts recorded yet. Deploy one with `eth-bp deploy`.");
+        return Ok(());
+    }
+
+    for (name, chain_id, address) in rows {
+        println!("{:<20} chain_id={:<8} {:#x}", name, chain_id, address);
     }
-    
     Ok(())
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 //! Development utilities command implementation
 
 use colored::Colorize;
-use crate::{Cli, DevSubcommands, Terminal};
+use crate::{Cli, DevSubcommands, ToolchainSubcommands, Terminal};
 use crate::utils::{create_spinner, Messenger};
 use ethereum_boilerplate_utils::{Config, Result, format_address_display};
 use alloy::signers::{Signer, local::PrivateKeySigner};
@@ -10,6 +10,9 @@ use std::fs;
 use tokio::process::Command;
 use ethereum_boilerplate_shared::{ContractTemplate};
 use clap::Parser;
+use super::toolchain;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{postgres::PgPoolOptions, Row};
 
 /// Обработка dev подкоманд
 #[allow(dead_code)]
@@ -20,7 +23,65 @@ pub async fn handle_dev_command(config: Config, action: DevSubcommands) -> Resul
     match action {
         DevSubcommands::GenerateWallet => generate_wallet(&ui).await,
         DevSubcommands::RunNode { port } => run_local_node(&ui, port, 0).await,
-        DevSubcommands::Migrate { rollback } => migrate_database(&ui, config, rollback).await,
+        DevSubcommands::Migrate { rollback, steps } => migrate_database(&ui, config, rollback, steps).await,
+        DevSubcommands::Doctor => doctor(&ui).await,
+        DevSubcommands::Toolchain { action } => handle_toolchain_command(&ui, &action).await,
+        DevSubcommands::Stats { since, limit, json } => show_api_stats(&ui, config, since, limit, json).await,
+    }
+}
+
+/// Reports installed-vs-expected versions of the managed dev toolchain.
+pub async fn doctor(ui: &Cli) -> Result<()> {
+    println!("{}", "🩺 Toolchain doctor:".bright_green().bold());
+    for status in toolchain::doctor().await {
+        let mark = if status.installed { "✅".green() } else { "❌".red() };
+        let installed = toolchain::installed_version(&status.tool).unwrap_or_else(|| "unknown".to_string());
+        println!(
+            "  {} {:<8} expected {:<10} installed {:<10} {}",
+            mark,
+            status.tool,
+            status.expected_version,
+            installed,
+            status.resolved_path.unwrap_or_else(|| "not installed".dimmed().to_string()),
+        );
+    }
+    ui.success("Run `eth-bp dev run-node` to auto-install missing tools.");
+    Ok(())
+}
+
+/// `eth-bp dev toolchain install/list/which` -- the explicit counterpart to
+/// `toolchain::ensure`'s implicit auto-install from `run_local_node`.
+pub async fn handle_toolchain_command(ui: &Cli, action: &ToolchainSubcommands) -> Result<()> {
+    match action {
+        ToolchainSubcommands::Install { tool } => {
+            let targets: Vec<String> = match tool {
+                Some(t) => vec![t.clone()],
+                None => vec!["anvil".to_string(), "geth".to_string(), "solc".to_string()],
+            };
+
+            for t in targets {
+                let pb = create_spinner(format!("Устанавливаем {}...", t));
+                match toolchain::install(&t).await {
+                    Ok(path) => {
+                        pb.finish_and_clear();
+                        ui.success(&format!("{} установлен: {}", t, path.display()));
+                    }
+                    Err(e) => {
+                        pb.finish_and_clear();
+                        Messenger::fail(ui, &format!("Не удалось установить {}: {}", t, e));
+                    }
+                }
+            }
+            Ok(())
+        }
+        ToolchainSubcommands::List => doctor(ui).await,
+        ToolchainSubcommands::Which { tool } => {
+            match toolchain::resolve(tool) {
+                Some(path) => println!("{}", path.display()),
+                None => Messenger::fail(ui, &format!("{} is not installed and not on PATH", tool)),
+            }
+            Ok(())
+        }
     }
 }
 
@@ -55,10 +116,8 @@ pub async fn generate_wallet(ui: &Cli) -> Result<()> {
 async fn create_contract(ui: &Cli, contract_type: String, name: String) -> Result<()> {
     let pb = create_spinner(format!("Подготовка шаблона {}...", contract_type));
 
-    let content = match ContractTemplate::parse(&contract_type) {
-        Some(ContractTemplate::Erc20) => templates::erc20(&name),
-        Some(ContractTemplate::Erc721) => templates::erc721(&name),
-        Some(ContractTemplate::Custom) => templates::custom(&name),
+    let template = match ContractTemplate::parse(&contract_type) {
+        Some(t) => t,
         None => {
             pb.finish_and_clear();
             let available_templates = ContractTemplate::all()
@@ -71,6 +130,25 @@ async fn create_contract(ui: &Cli, contract_type: String, name: String) -> Resul
         }
     };
 
+    if template.is_stylus() {
+        let crate_dir = format!("contracts/{}", templates::crate_name(&name));
+        fs::create_dir_all(format!("{}/src", crate_dir))?;
+        fs::write(format!("{}/Cargo.toml", crate_dir), templates::stylus_cargo_toml(&name))?;
+        fs::write(format!("{}/src/lib.rs", crate_dir), templates::stylus_erc20(&name))?;
+
+        pb.finish_with_message("Stylus-крейт создан!");
+        ui.success(&format!("Контракт сохранен в: {}", crate_dir.cyan()));
+        return Ok(());
+    }
+
+    let content = match template {
+        ContractTemplate::Erc20 => templates::erc20(&name),
+        ContractTemplate::Erc721 => templates::erc721(&name),
+        ContractTemplate::Erc1155 => templates::erc1155(&name),
+        ContractTemplate::Custom => templates::custom(&name),
+        ContractTemplate::Stylus => unreachable!(),
+    };
+
     fs::create_dir_all("contracts")?;
     let path = format!("contracts/{}.sol", name);
     fs::write(&path, content)?;
@@ -86,14 +164,27 @@ pub async fn run_local_node(ui: &Cli, port: u16, accounts: u32) -> Result<()> {
     // 1. Проверяем доступность инструментов
     let port_str = port.to_string();
     let accounts_str = accounts.to_string();
-    let (cmd, args) = if check_cmd("anvil").await {
-        ("anvil", vec![
+
+    let anvil_path = if check_cmd("anvil").await {
+        Some("anvil".to_string())
+    } else {
+        match toolchain::ensure("anvil").await {
+            Ok(path) => Some(path.to_string_lossy().to_string()),
+            Err(e) => {
+                Messenger::warn(ui, &format!("Не удалось установить anvil автоматически: {}", e));
+                None
+            }
+        }
+    };
+
+    let (cmd, args): (String, Vec<&str>) = if let Some(anvil) = &anvil_path {
+        (anvil.clone(), vec![
             "--port", &port_str,
             "--accounts", &accounts_str,
             "--state-interval", "10"
         ])
     } else if check_cmd("npx").await {
-        ("npx", vec!["hardhat", "node", "--port", &port_str])
+        ("npx".to_string(), vec!["hardhat", "node", "--port", &port_str])
     } else {
         Messenger::fail(ui, "Ни Foundry (anvil), ни Hardhat не найдены. Установите один из них.");
         return Ok(());
@@ -101,7 +192,7 @@ pub async fn run_local_node(ui: &Cli, port: u16, accounts: u32) -> Result<()> {
 
     println!("🚀 Запуск локальной ноды ({}) на порту {}...", cmd.bright_green(), port);
 
-    let mut child = Command::new(cmd)
+    let mut child = Command::new(&cmd)
         .args(args)
         .spawn()
         .map_err(|e| ethereum_boilerplate_utils::UtilsError::internal(e.to_string()))?;
@@ -116,28 +207,250 @@ pub async fn run_local_node(ui: &Cli, port: u16, accounts: u32) -> Result<()> {
 
 // --- Миграции БД ---
 
-pub async fn migrate_database(ui: &Cli, config: Config, rollback: bool) -> Result<()> {
+/// Directory `apply_pending_migrations`/`rollback`/`migration_status` read
+/// `NNNN_name.{up,down}.sql` pairs from -- the same layout and
+/// `schema_migrations` tracking table as the server's `DatabaseService`
+/// (`crates/server/src/database.rs`), so `eth-bp dev
+/// migrate` and the server never disagree about what's been applied.
+const MIGRATIONS_DIR: &str = "./migrations";
+
+pub async fn migrate_database(ui: &Cli, config: Config, rollback: bool, steps: u32) -> Result<()> {
     let db_url = config.database_url.ok_or_else(|| {
         Messenger::fail(ui, "DATABASE_URL не настроен в конфигурации.");
         ethereum_boilerplate_utils::UtilsError::config_error("Missing DB URL")
     })?;
+    let dir = std::path::Path::new(MIGRATIONS_DIR);
+
+    if !rollback {
+        let pb = create_spinner("Применение миграций...");
+        let result = crate::migrations::apply_pending_migrations(&db_url, dir).await;
+        pb.finish_and_clear();
+
+        match result {
+            Ok(applied) if applied.is_empty() => ui.success("Нет новых миграций для применения."),
+            Ok(applied) => ui.success(&format!(
+                "Применено {} миграции(й): {:?}.",
+                applied.len(),
+                applied
+            )),
+            Err(e) => Messenger::fail(ui, &format!("Ошибка при выполнении миграции: {}", e)),
+        }
+        return report_migration_status(&db_url, dir).await;
+    }
+
+    let pb = create_spinner(format!("Откат {} миграции(й)...", steps));
+    let result = crate::migrations::rollback(&db_url, dir, steps as usize).await;
+    pb.finish_and_clear();
+
+    match result {
+        Ok(reverted) if reverted.is_empty() => ui.success("Нет применённых миграций для отката."),
+        Ok(reverted) => ui.success(&format!(
+            "Откат {} миграции(й) успешно завершен: {:?}.",
+            reverted.len(),
+            reverted
+        )),
+        Err(e) => Messenger::fail(ui, &format!("Откат остановлен: {}", e)),
+    }
+    report_migration_status(&db_url, dir).await
+}
+
+/// Prints the applied-vs-pending view after a run/rollback, so
+/// `migration_status` has a real call site instead of sitting unused.
+async fn report_migration_status(db_url: &str, dir: &std::path::Path) -> Result<()> {
+    let statuses = crate::migrations::migration_status(db_url, dir).await?;
+    for status in &statuses {
+        let mark = if status.applied { "✅" } else { "⏳" };
+        let warn = if status.checksum_mismatch { " (checksum mismatch!)" } else { "" };
+        println!("  {} {:04}_{}{}", mark, status.version, status.name, warn);
+    }
+    Ok(())
+}
+
+// --- Статистика API (таблица api_logs) ---
+
+/// Parses a relative window like `"24h"`, `"7d"`, `"30m"` into a cutoff
+/// timestamp; defaults to 24 hours when `since` is `None`.
+fn parse_since(since: Option<&str>) -> Result<DateTime<Utc>> {
+    let since = since.unwrap_or("24h");
+    let (amount, unit) = since.split_at(since.len().saturating_sub(1));
+    let amount: i64 = amount.parse().map_err(|_| {
+        ethereum_boilerplate_utils::UtilsError::parse(format!("invalid --since window: {}", since))
+    })?;
 
-    let pb = create_spinner("Выполнение миграций SQLx...");
+    let duration = match unit {
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        _ => {
+            return Err(ethereum_boilerplate_utils::UtilsError::parse(format!(
+                "--since must end in 'm', 'h', or 'd' (got: {})",
+                since
+            )))
+        }
+    };
 
-    let action = if rollback { "rollback" } else { "run" };
-    let status = Command::new("sqlx")
-        .args(&["migrate", action])
-        .env("DATABASE_URL", db_url)
-        .status()
-        .await?;
+    Ok(Utc::now() - duration)
+}
+
+struct EndpointLatency {
+    endpoint: String,
+    call_count: i64,
+    p50_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+}
+
+struct AddressCallCount {
+    address: String,
+    call_count: i64,
+}
+
+struct HourlyCallVolume {
+    hour: DateTime<Utc>,
+    call_count: i64,
+}
+
+/// `eth-bp dev stats` -- latency percentiles, hourly request volume, and
+/// top addresses read back from `api_logs` (written by the server's
+/// `DatabaseService::log_api_call` on every request). Computes percentiles
+/// in SQL via `percentile_cont` rather than pulling every row into memory.
+pub async fn show_api_stats(ui: &Cli, config: Config, since: Option<String>, limit: i64, json: bool) -> Result<()> {
+    let db_url = config.database_url.ok_or_else(|| {
+        Messenger::fail(ui, "DATABASE_URL не настроен в конфигурации.");
+        ethereum_boilerplate_utils::UtilsError::config_error("Missing DB URL")
+    })?;
+    let since = parse_since(since.as_deref())?;
+
+    let pb = create_spinner("Запрос статистики из api_logs...");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&db_url)
+        .await
+        .map_err(|e| ethereum_boilerplate_utils::UtilsError::database(e.to_string()))?;
+
+    let latency_rows = sqlx::query(
+        r#"
+        SELECT
+            endpoint,
+            COUNT(*) AS call_count,
+            percentile_cont(0.5) WITHIN GROUP (ORDER BY response_time_ms) AS p50_ms,
+            percentile_cont(0.95) WITHIN GROUP (ORDER BY response_time_ms) AS p95_ms,
+            percentile_cont(0.99) WITHIN GROUP (ORDER BY response_time_ms) AS p99_ms
+        FROM api_logs
+        WHERE created_at >= $1
+        GROUP BY endpoint
+        ORDER BY call_count DESC
+        "#,
+    )
+    .bind(since)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ethereum_boilerplate_utils::UtilsError::database(e.to_string()))?;
+
+    let latencies: Vec<EndpointLatency> = latency_rows
+        .into_iter()
+        .map(|row| EndpointLatency {
+            endpoint: row.get("endpoint"),
+            call_count: row.get("call_count"),
+            p50_ms: row.get("p50_ms"),
+            p95_ms: row.get("p95_ms"),
+            p99_ms: row.get("p99_ms"),
+        })
+        .collect();
+
+    let address_rows = sqlx::query(
+        r#"
+        SELECT address, COUNT(*) AS call_count
+        FROM api_logs
+        WHERE created_at >= $1 AND address IS NOT NULL
+        GROUP BY address
+        ORDER BY call_count DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(since)
+    .bind(limit)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ethereum_boilerplate_utils::UtilsError::database(e.to_string()))?;
+
+    let top_addresses: Vec<AddressCallCount> = address_rows
+        .into_iter()
+        .map(|row| {
+            let address_bytes: &[u8] = row.get("address");
+            let address = if address_bytes.len() == 20 {
+                format!("{:#x}", alloy::primitives::Address::from_slice(address_bytes))
+            } else {
+                "<invalid>".to_string()
+            };
+            AddressCallCount { address, call_count: row.get("call_count") }
+        })
+        .collect();
+
+    let volume_rows = sqlx::query(
+        r#"
+        SELECT date_trunc('hour', created_at) AS bucket, COUNT(*) AS call_count
+        FROM api_logs
+        WHERE created_at >= $1
+        GROUP BY bucket
+        ORDER BY bucket
+        "#,
+    )
+    .bind(since)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ethereum_boilerplate_utils::UtilsError::database(e.to_string()))?;
+
+    let volume: Vec<HourlyCallVolume> = volume_rows
+        .into_iter()
+        .map(|row| HourlyCallVolume { hour: row.get("bucket"), call_count: row.get("call_count") })
+        .collect();
 
     pb.finish_and_clear();
 
-    if status.success() {
-        ui.success(&format!("Миграция ({}) успешно завершена.", action));
-    } else {
-        Messenger::fail(ui, "Ошибка при выполнении миграции. Проверьте статус базы данных.");
+    if json {
+        let payload = serde_json::json!({
+            "since": since.to_rfc3339(),
+            "endpoint_latency": latencies.iter().map(|l| serde_json::json!({
+                "endpoint": l.endpoint,
+                "call_count": l.call_count,
+                "p50_ms": l.p50_ms,
+                "p95_ms": l.p95_ms,
+                "p99_ms": l.p99_ms,
+            })).collect::<Vec<_>>(),
+            "top_addresses": top_addresses.iter().map(|a| serde_json::json!({
+                "address": a.address,
+                "call_count": a.call_count,
+            })).collect::<Vec<_>>(),
+            "calls_over_time": volume.iter().map(|v| serde_json::json!({
+                "hour": v.hour.to_rfc3339(),
+                "call_count": v.call_count,
+            })).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+
+    println!("{}", "Endpoint Latency (ms):".bold().underline());
+    println!("  {:<30} {:>8} {:>10} {:>10} {:>10}", "Endpoint", "Calls", "p50", "p95", "p99");
+    for l in &latencies {
+        println!(
+            "  {:<30} {:>8} {:>10.1} {:>10.1} {:>10.1}",
+            l.endpoint, l.call_count, l.p50_ms, l.p95_ms, l.p99_ms
+        );
+    }
+
+    println!("\n{}", "Top Addresses:".bold().underline());
+    for a in &top_addresses {
+        println!("  {:<44} {:>8} calls", a.address.bright_green(), a.call_count);
+    }
+
+    println!("\n{}", "Request Volume by Hour:".bold().underline());
+    for v in &volume {
+        println!("  {:<25} {:>8}", v.hour.format("%Y-%m-%d %H:00 UTC"), v.call_count);
     }
+
+    ui.success("Статистика API получена успешно.");
     Ok(())
 }
 
@@ -172,7 +485,100 @@ contract {name} is ERC721 {{
 }}"#)
     }
 
+    pub fn erc1155(name: &str) -> String {
+        format!(r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import "@openzeppelin/contracts/token/ERC1155/ERC1155.sol";
+
+contract {name} is ERC1155 {{
+    constructor() ERC1155("") {{}}
+}}"#)
+    }
+
     pub fn custom(name: &str) -> String {
         format!("// SPDX-License-Identifier: MIT\npragma solidity ^0.8.20;\n\ncontract {name} {{\n    // Your logic here\n}}")
     }
+
+    /// Crate-name-safe slug for a Stylus contract package.
+    pub fn crate_name(name: &str) -> String {
+        name.to_lowercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+    }
+
+    pub fn stylus_cargo_toml(name: &str) -> String {
+        format!(
+            r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+stylus-sdk = "0.6"
+alloy-primitives = "0.7"
+
+[lib]
+crate-type = ["lib", "cdylib"]
+
+[profile.release]
+codegen-units = 1
+panic = "abort"
+opt-level = "z"
+strip = true
+lto = true
+"#,
+            crate_name = crate_name(name)
+        )
+    }
+
+    /// Minimal ERC-20-shaped Stylus contract implemented in Rust, compiled
+    /// to `wasm32-unknown-unknown` and deployed via `cargo stylus deploy`.
+    pub fn stylus_erc20(name: &str) -> String {
+        format!(
+            r#"//! {name}: a Rust smart contract targeting Arbitrum Stylus.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+use alloy_primitives::{{Address, U256}};
+use stylus_sdk::{{prelude::*, storage::{{StorageMap, StorageU256}}}};
+
+#[storage]
+#[entrypoint]
+pub struct {name} {{
+    balances: StorageMap<Address, StorageU256>,
+    total_supply: StorageU256,
+}}
+
+#[public]
+impl {name} {{
+    pub fn balance_of(&self, account: Address) -> U256 {{
+        self.balances.get(account)
+    }}
+
+    pub fn total_supply(&self) -> U256 {{
+        self.total_supply.get()
+    }}
+
+    pub fn mint(&mut self, to: Address, amount: U256) {{
+        let balance = self.balances.get(to);
+        self.balances.setter(to).set(balance + amount);
+        let supply = self.total_supply.get();
+        self.total_supply.set(supply + amount);
+    }}
+
+    pub fn transfer(&mut self, to: Address, amount: U256) -> bool {{
+        let from = self.vm().msg_sender();
+        let from_balance = self.balances.get(from);
+        if from_balance < amount {{
+            return false;
+        }}
+        self.balances.setter(from).set(from_balance - amount);
+        let to_balance = self.balances.get(to);
+        self.balances.setter(to).set(to_balance + amount);
+        true
+    }}
+}}
+"#,
+            name = name
+        )
+    }
 }
\ No newline at end of file
@@ -6,12 +6,16 @@ use ethereum_boilerplate_utils::{
     print_banner, print_success, print_error, create_progress_bar, // Проверьте, что они pub там
     Config, Result, UtilsError
 };
+use ethereum_boilerplate_utils::config::DockerConfig;
 use ethereum_boilerplate_shared::paths::FRONTEND_PATH;
+use crate::commands::release;
 use tokio::process::Command;
 use std::path::Path;
 use std::process::ExitStatus;
 
-pub async fn run_frontend(_config: Config, port: u16, build: bool) -> Result<()> {
+const WASM_TARGET_TRIPLE: &str = "wasm32-unknown-unknown";
+
+pub async fn run_frontend(config: Config, port: u16, build: bool, upload: bool, docker: bool) -> Result<()> {
     print_banner();
 
     // 1. Валидация окружения
@@ -23,6 +27,32 @@ pub async fn run_frontend(_config: Config, port: u16, build: bool) -> Result<()>
         print_success("Запуск сборки фронтенда (release)...");
         execute_trunk(&["build", "--release"], Some("Сборка артефактов")).await?;
         print_success("Фронтенд собран в crates/frontend/dist/");
+
+        // Фиксируем хэши артефактов в manifest.json, чтобы релиз был
+        // воспроизводимым и проверяемым, а не просто файлами на диске.
+        let dist_dir = Path::new(FRONTEND_PATH).join("dist");
+        let manifest = release::generate_manifest(&dist_dir)?;
+        print_success(&format!("Сформирован manifest.json ({} файлов)", manifest.len()));
+
+        if upload {
+            let release_config = config.release.as_ref().ok_or_else(|| {
+                UtilsError::config_error(
+                    "Секция `release` не задана в config.json — укажите s3_endpoint/s3_bucket/s3_region/s3_access_key/s3_secret_key",
+                )
+            })?;
+
+            let ref_name = current_git_ref().await;
+            print_success(&format!(
+                "Публикация артефактов в {}/{} ({}/{})",
+                release_config.s3_bucket, ref_name, ref_name, WASM_TARGET_TRIPLE
+            ));
+            release::upload_artifacts(release_config, &dist_dir, &manifest, &ref_name, WASM_TARGET_TRIPLE).await?;
+            print_success("Артефакты загружены в S3-совместимое хранилище");
+        }
+
+        if docker {
+            build_frontend_image(&config, &dist_dir).await?;
+        }
     } else {
         start_dev_server(port).await?;
     }
@@ -30,6 +60,49 @@ pub async fn run_frontend(_config: Config, port: u16, build: bool) -> Result<()>
     Ok(())
 }
 
+/// Пишет минимальный Dockerfile (статика `dist/` + nginx) рядом с
+/// собранными артефактами и собирает образ через `docker build`, чтобы
+/// скомпилированный WASM-фронтенд можно было задеплоить как контейнер без
+/// ручного написания Docker-файлов.
+async fn build_frontend_image(config: &Config, dist_dir: &Path) -> Result<()> {
+    let default_docker_config = DockerConfig { image_tag: None, base_image: None, serve_port: None };
+    let docker_config = config.docker.as_ref().unwrap_or(&default_docker_config);
+
+    let dockerfile = format!(
+        "FROM {base_image}\nCOPY dist/ /usr/share/nginx/html/\nEXPOSE {port}\nENTRYPOINT [\"nginx\", \"-g\", \"daemon off;\"]\n",
+        base_image = docker_config.base_image(),
+        port = docker_config.serve_port(),
+    );
+    let dockerfile_path = dist_dir.join("..").join("Dockerfile");
+    std::fs::write(&dockerfile_path, dockerfile)
+        .map_err(|e| UtilsError::internal(format!("Не удалось записать Dockerfile: {}", e)))?;
+
+    print_success(&format!("Сборка Docker-образа {}...", docker_config.tag()));
+    let status = Command::new("docker")
+        .args(["build", "-t", docker_config.tag(), "-f", "Dockerfile", "."])
+        .current_dir(FRONTEND_PATH)
+        .status()
+        .await
+        .map_err(|e| UtilsError::internal(format!("Не удалось запустить Docker: {}", e)))?;
+
+    handle_exit_status(status, "Docker build")?;
+    print_success(&format!("Образ собран: {}", docker_config.tag()));
+    Ok(())
+}
+
+/// Имя текущей git-ветки (или "unknown"), используется как префикс ключа
+/// при публикации артефактов: `<ref_name>/<target_triple>/...`.
+async fn current_git_ref() -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .await;
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).trim().to_string(),
+        _ => "unknown".to_string(),
+    }
+}
+
 /// Проверка существования директории фронтенда
 fn ensure_frontend_exists() -> Result<()> {
     if !Path::new(FRONTEND_PATH).is_dir() {
@@ -11,3 +11,7 @@ pub mod wallet;
 pub mod network;
 pub mod config;
 pub mod dev;
+pub mod toolchain;
+pub mod rpc;
+pub mod bench;
+pub mod release;
@@ -3,75 +3,118 @@
 use crate::{print_banner, print_success, print_error, create_progress_bar};
 use ethereum_boilerplate_utils::{Config, Result};
 use ethereum_boilerplate_utils::formatting::format_gas_price;
+use ethereum_boilerplate_utils::network::{create_provider_with_retry, RetryConfig, NodeHealth, SyncState, STALE_BLOCK_AGE_SECS};
 use colored::Colorize;
 use crate::NetworkCommands;
 
 pub async fn handle_network_command(config: Config, action: NetworkCommands) -> Result<()> {
     print_banner();
-    
+
     match action {
-        NetworkCommands::Status => show_network_status(config).await?,
+        NetworkCommands::Status { json } => show_network_status(config, json).await?,
         NetworkCommands::GasPrice => show_gas_price(config).await?,
         NetworkCommands::BlockNumber => show_block_number(config).await?,
         NetworkCommands::Switch { network } => switch_network(network).await?,
         NetworkCommands::List => list_networks().await?,
     }
-    
+
     Ok(())
 }
 
-async fn show_network_status(config: Config) -> Result<()> {
-    let progress = create_progress_bar(3);
-    
+async fn show_network_status(config: Config, json: bool) -> Result<()> {
+    let progress = create_progress_bar(2);
+
     progress.set_message("Connecting to network...");
     progress.inc(1);
-    
-    // Check network health
-    let provider = ethereum_boilerplate_utils::network::create_provider(&config.network)?;
-    ethereum_boilerplate_utils::network::check_provider_health(&provider).await?;
-    
-    progress.set_message("Getting network info...");
-    progress.inc(1);
-    
-    // Get network information
-    let chain_id = ethereum_boilerplate_utils::network::get_chain_id(&provider).await?;
-    let block_number = ethereum_boilerplate_utils::network::get_latest_block_number(&provider).await?;
-    
-    progress.set_message("Finalizing...");
+
+    // Retrying transient failures instead of aborting on the first
+    // rate-limit/timeout hiccup.
+    let client = create_provider_with_retry(&config.network, RetryConfig::from_config(&config))?;
+
+    progress.set_message("Checking peer count, sync state, and chain health...");
     progress.inc(1);
-    
+
+    let health = client.get_node_health(config.network.chain_id).await?;
+
     progress.finish();
-    
-    println!("Network Status:");
-    println!("  Name:       {}", config.network.name);
-    println!("  Chain ID:   {}", chain_id);
-    println!("  Block:       {}", block_number);
-    println!("  RPC URL:     {}", config.network.rpc_url);
-    println!("  Explorer:    {}", config.network.explorer_url);
-    
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&health)?);
+        return Ok(());
+    }
+
+    print_node_health_table(&config, &health);
+
     print_success("Network status retrieved successfully!");
     Ok(())
 }
 
+fn print_node_health_table(config: &Config, health: &NodeHealth) {
+    let overall = if health.is_healthy() { "HEALTHY".bright_green().bold() } else { "DEGRADED".bright_red().bold() };
+
+    println!("Network Status: {}", overall);
+    println!("  Name:              {}", config.network.name);
+    println!("  RPC URL:           {}", config.network.rpc_url);
+    println!("  Explorer:          {}", config.network.explorer_url);
+
+    let chain_id_line = format!("{} (expected {})", health.chain_id, health.expected_chain_id);
+    if health.chain_id == health.expected_chain_id {
+        println!("  Chain ID:          {}", chain_id_line.green());
+    } else {
+        println!("  Chain ID:          {}", chain_id_line.red());
+    }
+
+    let peer_line = health.peer_count.to_string();
+    if health.peer_count > 0 {
+        println!("  Peers:             {}", peer_line.green());
+    } else {
+        println!("  Peers:             {}", peer_line.red());
+    }
+
+    match health.sync_state {
+        SyncState::Synced => println!("  Sync:              {}", "synced".green()),
+        SyncState::Syncing { current_block, highest_block, percent } => {
+            let line = format!("syncing {}/{} ({:.1}%)", current_block, highest_block, percent);
+            println!("  Sync:              {}", line.yellow());
+        }
+    }
+
+    println!("  Latest Block:      {}", health.latest_block);
+    let age_line = format!("{}s ago", health.latest_block_age_secs);
+    if health.latest_block_age_secs <= STALE_BLOCK_AGE_SECS {
+        println!("  Block Age:         {}", age_line.green());
+    } else {
+        println!("  Block Age:         {}", age_line.red());
+    }
+}
+
 async fn show_gas_price(config: Config) -> Result<()> {
-    let progress = create_progress_bar(2);
-    
+    let progress = create_progress_bar(3);
+
     progress.set_message("Getting gas price...");
     progress.inc(1);
-    
+
     // Get gas price
+    let client = create_provider_with_retry(&config.network, RetryConfig::from_config(&config))?;
+    let gas_price = client.get_gas_price().await?;
+
+    progress.set_message("Estimating EIP-1559 fees...");
+    progress.inc(1);
+
     let provider = ethereum_boilerplate_utils::network::create_provider(&config.network)?;
-    let gas_price = ethereum_boilerplate_utils::network::get_gas_price(&provider).await?;
-    
+    let eip1559 = ethereum_boilerplate_utils::network::estimate_eip1559_fees(&provider).await?;
+
     progress.set_message("Formatting...");
     progress.inc(1);
-    
+
     progress.finish();
-    
+
     println!("Gas Price Information:");
-    println!("  Current:   {}", format_gas_price(gas_price));
-    println!("  Wei:        {}", gas_price);
-    
+    println!("  Current (legacy):         {}", format_gas_price(gas_price));
+    println!("  Wei:                      {}", gas_price);
+    println!("  Max fee (EIP-1559):       {}", format_gas_price(eip1559.max_fee_per_gas));
+    println!("  Max priority fee:         {}", format_gas_price(eip1559.max_priority_fee_per_gas));
+
     print_success("Gas price retrieved successfully!");
     Ok(())
 }
@@ -83,8 +126,8 @@ async fn show_block_number(config: Config) -> Result<()> {
     progress.inc(1);
     
     // Get block number
-    let provider = ethereum_boilerplate_utils::network::create_provider(&config.network)?;
-    let block_number = ethereum_boilerplate_utils::network::get_latest_block_number(&provider).await?;
+    let client = create_provider_with_retry(&config.network, RetryConfig::from_config(&config))?;
+    let block_number = client.get_block_number().await?;
     
     progress.set_message("Formatting...");
     progress.inc(1);
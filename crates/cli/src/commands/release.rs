@@ -0,0 +1,187 @@
+//! Release packaging and S3-compatible artifact publishing.
+//!
+//! After `trunk build --release`, hashes every file under
+//! `crates/frontend/dist/` (sha256 + md5), writes a `dist/manifest.json`
+//! mapping path -> `{sha256, md5, size}`, and optionally pushes the
+//! artifacts and the manifest to an S3-compatible bucket keyed
+//! `<ref_name>/<target_triple>/...`, the way the Parity CI jobs lay out
+//! their release artifacts. This turns the build step into a reproducible,
+//! verifiable release rather than just leaving files on disk.
+
+use chrono::Utc;
+use ethereum_boilerplate_utils::{Result, UtilsError};
+use ethereum_boilerplate_utils::config::ReleaseConfig;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileDigest {
+    pub sha256: String,
+    pub md5: String,
+    pub size: u64,
+}
+
+pub type Manifest = BTreeMap<String, FileDigest>;
+
+/// Walks `dist_dir`, hashing every regular file, and writes `manifest.json`
+/// alongside them.
+pub fn generate_manifest(dist_dir: &Path) -> Result<Manifest> {
+    let mut manifest = Manifest::new();
+    collect_files(dist_dir, dist_dir, &mut manifest)?;
+
+    let manifest_path = dist_dir.join("manifest.json");
+    let content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| UtilsError::internal(format!("Failed to serialize manifest: {}", e)))?;
+    std::fs::write(&manifest_path, content)
+        .map_err(|e| UtilsError::internal(format!("Failed to write manifest.json: {}", e)))?;
+
+    Ok(manifest)
+}
+
+fn collect_files(root: &Path, dir: &Path, manifest: &mut Manifest) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| UtilsError::internal(format!("Failed to read {}: {}", dir.display(), e)))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| UtilsError::internal(e.to_string()))?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            collect_files(root, &path, manifest)?;
+            continue;
+        }
+        if path.file_name().and_then(|n| n.to_str()) == Some("manifest.json") {
+            continue;
+        }
+
+        let bytes = std::fs::read(&path)
+            .map_err(|e| UtilsError::internal(format!("Failed to read {}: {}", path.display(), e)))?;
+        let relative = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        manifest.insert(
+            relative,
+            FileDigest {
+                sha256: to_hex(&Sha256::digest(&bytes)),
+                md5: to_hex(&md5::compute(&bytes).0),
+                size: bytes.len() as u64,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Uploads every manifest entry plus `manifest.json` itself to the
+/// configured S3-compatible bucket, keyed `<ref_name>/<target_triple>/<path>`.
+pub async fn upload_artifacts(
+    release: &ReleaseConfig,
+    dist_dir: &Path,
+    manifest: &Manifest,
+    ref_name: &str,
+    target_triple: &str,
+) -> Result<()> {
+    let client = reqwest::Client::new();
+
+    for relative_path in manifest.keys() {
+        let bytes = std::fs::read(dist_dir.join(relative_path))
+            .map_err(|e| UtilsError::internal(format!("Failed to read {}: {}", relative_path, e)))?;
+        let key = format!("{}/{}/{}", ref_name, target_triple, relative_path);
+        put_object(&client, release, &key, bytes).await?;
+    }
+
+    let manifest_bytes = std::fs::read(dist_dir.join("manifest.json"))
+        .map_err(|e| UtilsError::internal(format!("Failed to read manifest.json: {}", e)))?;
+    let manifest_key = format!("{}/{}/manifest.json", ref_name, target_triple);
+    put_object(&client, release, &manifest_key, manifest_bytes).await?;
+
+    Ok(())
+}
+
+/// A minimal AWS Signature Version 4 `PUT` (single chunk, unsigned
+/// payload), enough to talk to S3-compatible object storage without
+/// pulling in the full AWS SDK.
+async fn put_object(client: &reqwest::Client, release: &ReleaseConfig, key: &str, body: Vec<u8>) -> Result<()> {
+    let endpoint = release.s3_endpoint.trim_end_matches('/');
+    let host = endpoint
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let url = format!("{}/{}/{}", endpoint, release.s3_bucket, key);
+
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date_stamp = now.format("%Y%m%d").to_string();
+
+    let canonical_uri = format!("/{}/{}", release.s3_bucket, key);
+    let canonical_headers = format!(
+        "host:{}\nx-amz-content-sha256:UNSIGNED-PAYLOAD\nx-amz-date:{}\n",
+        host, amz_date
+    );
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+    let canonical_request = format!(
+        "PUT\n{}\n\n{}\n{}\nUNSIGNED-PAYLOAD",
+        canonical_uri, canonical_headers, signed_headers
+    );
+
+    let credential_scope = format!("{}/{}/s3/aws4_request", date_stamp, release.s3_region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        credential_scope,
+        to_hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let signing_key = derive_signing_key(&release.s3_secret_key, &date_stamp, &release.s3_region);
+    let signature = to_hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        release.s3_access_key, credential_scope, signed_headers, signature
+    );
+
+    let response = client
+        .put(&url)
+        .header("Host", host)
+        .header("X-Amz-Date", &amz_date)
+        .header("X-Amz-Content-Sha256", "UNSIGNED-PAYLOAD")
+        .header("Authorization", authorization)
+        .body(body)
+        .send()
+        .await
+        .map_err(UtilsError::network)?;
+
+    if !response.status().is_success() {
+        return Err(UtilsError::internal(format!(
+            "S3 upload of {} failed with status {}",
+            key,
+            response.status()
+        )));
+    }
+
+    Ok(())
+}
+
+fn derive_signing_key(secret_key: &str, date_stamp: &str, region: &str) -> Vec<u8> {
+    let k_date = hmac_sha256(format!("AWS4{}", secret_key).as_bytes(), date_stamp.as_bytes());
+    let k_region = hmac_sha256(&k_date, region.as_bytes());
+    let k_service = hmac_sha256(&k_region, b"s3");
+    hmac_sha256(&k_service, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,64 @@
+//! JSON-RPC server command implementation
+//!
+//! The `server` crate exposes its deploy/mint/transfer/balance/history
+//! operations both as REST routes and as named JSON-RPC methods on the same
+//! socket (`POST /rpc`). This command launches that same binary but frames
+//! the experience around the RPC surface, for callers that want a single
+//! endpoint to drive programmatically instead of the REST routes.
+
+use crate::{Cli, Terminal, create_spinner};
+use crate::utils::Messenger;
+use ethereum_boilerplate_utils::{Config, Result, UtilsError};
+use clap::Parser;
+use tokio::process::Command;
+use std::path::Path;
+
+pub async fn run_rpc_server(config: Config, port: u16) -> Result<()> {
+    let ui = Cli::parse();
+    ui.banner();
+
+    let server_dir = Path::new("crates/server");
+    if !server_dir.exists() {
+        let msg = "'server' crate not found. Make sure you are in the project root.";
+        ui.fail(msg);
+        return Err(UtilsError::config_error(msg));
+    }
+
+    let pb = create_spinner("Preparing environment and building server...");
+
+    let db_url = config.database_url.unwrap_or_else(|| "postgres://localhost/db".into());
+
+    let build_status = Command::new("cargo")
+        .args(&["build", "-p", "server"])
+        .status()
+        .await?;
+
+    if !build_status.success() {
+        pb.finish_and_clear();
+        ui.fail("Server compilation failed.");
+        return Err(UtilsError::internal("Build failed"));
+    }
+
+    pb.finish_with_message("Server ready to launch!");
+
+    ui.success(&format!("Starting JSON-RPC server on http://localhost:{}/rpc", port));
+    ui.info("Methods: get_balance, deploy_contract, mint_tokens, transfer_tokens, transaction_history");
+
+    let binary_path = Path::new("target/debug/server");
+
+    let mut child = Command::new(binary_path)
+        .arg("--port")
+        .arg(port.to_string())
+        .env("DATABASE_URL", db_url)
+        .env("RUST_LOG", "info")
+        .spawn()
+        .map_err(|e| UtilsError::internal(format!("Failed to launch binary file: {}. Run cargo build.", e)))?;
+
+    let status = child.wait().await?;
+
+    if !status.success() {
+        ui.fail("RPC server terminated with error.");
+    }
+
+    Ok(())
+}
@@ -1,11 +1,92 @@
 //! Test command implementation
 use crate::{Cli, Terminal};
-use crate::utils::{create_spinner, Messenger}; // Assume spinner is available here
+use crate::utils::{create_spinner, is_installed, Messenger}; // Assume spinner is available here
 use ethereum_boilerplate_utils::{Result, UtilsError};
 use colored::*;
 use clap::Parser;
 use tokio::process::Command;
 use std::process::Stdio;
+use std::time::Instant;
+
+/// Which browser `wasm-pack test --headless` should drive. `Node` skips a
+/// real browser and runs the suite under `wasm-pack test --node` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WasmBrowser {
+    Chrome,
+    Firefox,
+    Safari,
+    Node,
+}
+
+/// Auto-detection order when `--browser` isn't given: prefer an actual
+/// browser over the `node` fallback, Firefox first since `wasm-pack`'s own
+/// docs default to it.
+const WASM_BROWSER_PRIORITY: [WasmBrowser; 4] =
+    [WasmBrowser::Firefox, WasmBrowser::Chrome, WasmBrowser::Safari, WasmBrowser::Node];
+
+impl WasmBrowser {
+    fn parse(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "chrome" => Ok(Self::Chrome),
+            "firefox" => Ok(Self::Firefox),
+            "safari" => Ok(Self::Safari),
+            "node" => Ok(Self::Node),
+            other => Err(UtilsError::validation_error(format!(
+                "Unknown --browser '{}' (expected chrome/firefox/safari/node)", other
+            ))),
+        }
+    }
+
+    fn wasm_pack_flag(self) -> &'static str {
+        match self {
+            Self::Chrome => "--chrome",
+            Self::Firefox => "--firefox",
+            Self::Safari => "--safari",
+            Self::Node => "--node",
+        }
+    }
+
+    /// The binary `wasm-pack` actually shells out to for this browser, used
+    /// to auto-detect which ones are usable on this machine.
+    fn driver_binary(self) -> &'static str {
+        match self {
+            Self::Chrome => "chromedriver",
+            Self::Firefox => "geckodriver",
+            Self::Safari => "safaridriver",
+            Self::Node => "node",
+        }
+    }
+
+    async fn is_available(self) -> bool {
+        is_installed(self.driver_binary()).await
+    }
+}
+
+/// Picks the browser to drive WASM tests with: an explicit `--browser` wins
+/// outright (and is an error if misspelled), otherwise probes
+/// `WASM_BROWSER_PRIORITY` in order and takes the first one whose driver is
+/// actually installed, returning `None` if nothing usable was found.
+async fn select_wasm_browser(requested: Option<&str>) -> Result<Option<WasmBrowser>> {
+    if let Some(name) = requested {
+        return Ok(Some(WasmBrowser::parse(name)?));
+    }
+
+    for candidate in WASM_BROWSER_PRIORITY {
+        if candidate.is_available().await {
+            return Ok(Some(candidate));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Result of one native or WASM test step, collected for optional report
+/// emission so CI can consume native and WASM jobs the same way.
+struct TestOutcome {
+    name: String,
+    passed: bool,
+    duration: std::time::Duration,
+}
 
 /// Run tests based on selected type
 pub async fn run_tests(
@@ -13,71 +94,112 @@ pub async fn run_tests(
     test_type: String,
     release: bool,
     verbose: bool,
+    browser: Option<String>,
+    no_wasm: bool,
+    report: Option<String>,
 ) -> Result<()> {
     // In this context, Cli is usually passed or parsed internally
-    let ui = Cli::parse(); 
-    
-    match test_type.as_str() {
-        "unit" => run_unit_tests(&ui, release, verbose).await,
-        "integration" => run_integration_tests(&ui, release, verbose).await,
+    let ui = Cli::parse();
+
+    let outcomes = match test_type.as_str() {
+        "unit" => run_unit_tests(&ui, release, verbose).await?,
+        "integration" => run_integration_tests(&ui, release, verbose, browser.as_deref(), no_wasm).await?,
         "all" => {
-            run_unit_tests(&ui, release, verbose).await?;
-            run_integration_tests(&ui, release, verbose).await
+            let mut outcomes = run_unit_tests(&ui, release, verbose).await?;
+            outcomes.extend(run_integration_tests(&ui, release, verbose, browser.as_deref(), no_wasm).await?);
+            outcomes
         }
         _ => {
             ui.fail(&format!("Unknown test type: {}", test_type));
-            Err(UtilsError::validation_error("Invalid test type"))
+            return Err(UtilsError::validation_error("Invalid test type"));
         }
+    };
+
+    if let Some(path) = report {
+        let format = if path.ends_with(".xml") { "junit" } else { "json" };
+        write_report(&path, format, &outcomes)?;
+        ui.info(&format!("Wrote {} test report to {}", format.to_uppercase(), path));
     }
+
+    Ok(())
 }
 
 /// Run unit tests for all packages
-async fn run_unit_tests(ui: &Cli, release: bool, verbose: bool) -> Result<()> {
+async fn run_unit_tests(ui: &Cli, release: bool, verbose: bool) -> Result<Vec<TestOutcome>> {
     ui.info("🚀 Running Unit tests...");
-    
+
     let packages = [
         "ethereum-boilerplate-utils",
         "ethereum-boilerplate-smart-contracts",
     ];
 
+    let mut outcomes = Vec::new();
     for pkg in packages {
-        execute_cargo_test(ui, pkg, release, verbose, None).await?;
+        outcomes.push(execute_cargo_test(ui, pkg, release, verbose, None).await?);
     }
 
     ui.success("All unit tests passed successfully!");
-    Ok(())
+    Ok(outcomes)
 }
 
 /// Run integration tests (Rust + WASM)
-async fn run_integration_tests(ui: &Cli, release: bool, verbose: bool) -> Result<()> {
+async fn run_integration_tests(
+    ui: &Cli,
+    release: bool,
+    verbose: bool,
+    browser: Option<&str>,
+    no_wasm: bool,
+) -> Result<Vec<TestOutcome>> {
     ui.info("📡 Running integration tests...");
 
     // 1. Standard integration tests
-    execute_cargo_test(ui, "integration", release, verbose, Some("--test")).await?;
+    let mut outcomes = vec![execute_cargo_test(ui, "integration", release, verbose, Some("--test")).await?];
+
+    if no_wasm {
+        ui.warn("Skipping WebAssembly tests (--no-wasm).");
+        return Ok(outcomes);
+    }
+
+    // 2. Frontend tests in a WASM environment, on whichever browser is
+    // actually available, instead of hard-coding Firefox.
+    if !check_wasm_pack().await {
+        ui.warn("Tool 'wasm-pack' not found. Skipping WASM tests.");
+        return Ok(outcomes);
+    }
+
+    match select_wasm_browser(browser).await? {
+        None => {
+            ui.warn("No WASM browser driver (geckodriver/chromedriver/safaridriver/node) found on PATH. Skipping WASM tests.");
+        }
+        Some(chosen) => {
+            let pb = create_spinner(&format!("Running WebAssembly tests ({:?}, headless)...", chosen));
+            let started = Instant::now();
+
+            let mut wasm_cmd = Command::new("wasm-pack");
+            wasm_cmd.args(&["test", "--headless", chosen.wasm_pack_flag()])
+                .current_dir("crates/frontend");
 
-    // 2. Frontend tests in WASM environment
-    if check_wasm_pack().await {
-        let pb = create_spinner("Running WebAssembly tests (headless)...");
-        
-        let mut wasm_cmd = Command::new("wasm-pack");
-        wasm_cmd.args(&["test", "--headless", "--firefox"])
-            .current_dir("crates/frontend");
+            if verbose { wasm_cmd.arg("--verbose"); }
 
-        if verbose { wasm_cmd.arg("--verbose"); }
+            let status = wasm_cmd.status().await?;
+            pb.finish_and_clear();
 
-        let status = wasm_cmd.status().await?;
-        pb.finish_and_clear();
+            let passed = status.success();
+            outcomes.push(TestOutcome {
+                name: format!("wasm::{:?}", chosen).to_lowercase(),
+                passed,
+                duration: started.elapsed(),
+            });
 
-        if !status.success() {
-            ui.fail("WebAssembly tests failed.");
-            return Err(UtilsError::internal("WASM tests failed"));
+            if !passed {
+                ui.fail("WebAssembly tests failed.");
+                return Err(UtilsError::internal("WASM tests failed"));
+            }
         }
-    } else {
-        ui.warn("Tool 'wasm-pack' not found. Skipping WASM tests.");
     }
 
     ui.success("Integration tests passed successfully!");
-    Ok(())
+    Ok(outcomes)
 }
 
 // --- Helper Functions ---
@@ -88,9 +210,10 @@ async fn execute_cargo_test(
     release: bool,
     verbose: bool,
     mode: Option<&str>,
-) -> Result<()> {
+) -> Result<TestOutcome> {
     let msg = format!("Testing {}...", target);
-    
+    let started = Instant::now();
+
     // Manage spinner or direct output
     let pb = if !verbose {
         Some(create_spinner(&msg))
@@ -128,7 +251,7 @@ async fn execute_cargo_test(
         return Err(UtilsError::internal(format!("Tests failed for {}", target)));
     }
 
-    Ok(())
+    Ok(TestOutcome { name: target.to_string(), passed: true, duration: started.elapsed() })
 }
 
 async fn check_wasm_pack() -> bool {
@@ -139,4 +262,63 @@ async fn check_wasm_pack() -> bool {
         .status()
         .await
         .is_ok()
-}
\ No newline at end of file
+}
+
+/// Writes a machine-readable test report so CI can split native and WASM
+/// jobs the same way it would for any other language's test runner.
+fn write_report(path: &str, format: &str, outcomes: &[TestOutcome]) -> Result<()> {
+    let content = match format {
+        "json" => render_json_report(outcomes),
+        "junit" => render_junit_report(outcomes),
+        other => {
+            return Err(UtilsError::validation_error(format!(
+                "Unknown report format '{}' (expected json/junit)", other
+            )))
+        }
+    };
+
+    std::fs::write(path, content)
+        .map_err(|e| UtilsError::internal(format!("Failed to write report to {}: {}", path, e)))
+}
+
+fn render_json_report(outcomes: &[TestOutcome]) -> String {
+    let entries: Vec<String> = outcomes
+        .iter()
+        .map(|o| {
+            format!(
+                r#"{{"name":"{}","passed":{},"duration_ms":{}}}"#,
+                o.name,
+                o.passed,
+                o.duration.as_millis()
+            )
+        })
+        .collect();
+
+    format!("[{}]", entries.join(","))
+}
+
+fn render_junit_report(outcomes: &[TestOutcome]) -> String {
+    let failures = outcomes.iter().filter(|o| !o.passed).count();
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"eth-bp\" tests=\"{}\" failures=\"{}\">\n",
+        outcomes.len(),
+        failures
+    );
+
+    for o in outcomes {
+        xml.push_str(&format!(
+            "  <testcase name=\"{}\" time=\"{:.3}\"",
+            o.name,
+            o.duration.as_secs_f64()
+        ));
+
+        if o.passed {
+            xml.push_str(" />\n");
+        } else {
+            xml.push_str(&format!(">\n    <failure message=\"{} failed\" />\n  </testcase>\n", o.name));
+        }
+    }
+
+    xml.push_str("</testsuite>\n");
+    xml
+}
@@ -0,0 +1,287 @@
+//! Pinned-version installer for the external binaries the dev/deploy flows
+//! shell out to (`anvil`, `geth`, `solc`). Downloads the platform-matching
+//! release archive into a crate-managed bin directory so `eth-bp dev
+//! run-node` works on a clean machine without the user hand-installing
+//! Foundry/Hardhat. `eth-bp deploy` does not resolve its tools through
+//! this installer.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Directory (relative to the CLI's working directory) where pinned tool
+/// binaries are installed.
+const BIN_DIR: &str = ".eth-bp/bin";
+
+/// Records which (tool, version) pairs were actually installed by us, so
+/// `list`/`doctor` can report a pinned version even when the binary was
+/// resolved from a managed path rather than `PATH`. Persisted the same way
+/// `ContractManager` persists its deployment registry: a plain JSON file
+/// sitting next to the thing it describes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    installed: HashMap<String, String>,
+}
+
+fn manifest_path() -> PathBuf {
+    bin_dir().join("manifest.json")
+}
+
+fn load_manifest() -> Manifest {
+    std::fs::read_to_string(manifest_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn record_install(tool: &str, version: &str) {
+    let mut manifest = load_manifest();
+    manifest.installed.insert(tool.to_string(), version.to_string());
+    if let Ok(content) = serde_json::to_string_pretty(&manifest) {
+        let _ = std::fs::create_dir_all(bin_dir());
+        let _ = std::fs::write(manifest_path(), content);
+    }
+}
+
+/// The version we actually installed for `tool`, per the manifest -- `None`
+/// if we never installed it ourselves (e.g. it was already on `PATH`).
+pub fn installed_version(tool: &str) -> Option<String> {
+    load_manifest().installed.get(tool).cloned()
+}
+
+/// Known SHA-256 digest of the downloaded archive/binary for a pinned
+/// `(tool, version, platform)`, checked before the binary is trusted and
+/// extracted. Update alongside `pinned_version` when bumping a tool.
+fn known_checksum(tool: &str, version: &str, platform: &str) -> Option<&'static str> {
+    match (tool, version, platform) {
+        ("geth", "1.14.0", "linux") => Some("f1ae1a6774e61bd8398d75db884aa94588b76264b1c2de7e880b0face7dab3eb"),
+        ("anvil", "0.2.0", "linux") => Some("4295d2e94bad3586e1945af858048e8fe16c5169490c0cccc4cd90492959e364"),
+        ("solc", "0.8.24", "linux") => Some("5168724c4d2cab994947913ce71b8d8ab9d704c251c3b271e7c6ca1d30e2d6a7"),
+        _ => None,
+    }
+}
+
+fn verify_checksum(path: &Path, expected: &str) -> Result<(), String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    let actual = hex::encode(hasher.finalize());
+    if actual != expected {
+        return Err(format!("checksum mismatch: expected {}, got {}", expected, actual));
+    }
+    Ok(())
+}
+
+/// Pinned versions, overridable via environment variables so users can
+/// bump a single tool without waiting on a new CLI release.
+pub fn pinned_version(tool: &str) -> String {
+    let env_key = format!("{}_BUILD", tool.to_uppercase());
+    let default = match tool {
+        "geth" => "1.14.0",
+        "anvil" => "0.2.0",
+        "solc" => "0.8.24",
+        _ => "latest",
+    };
+    std::env::var(env_key).unwrap_or_else(|_| default.to_string())
+}
+
+/// Maps the host platform to the vendor's archive naming scheme.
+fn platform_tag() -> Result<&'static str, String> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", _) => Ok("linux"),
+        ("macos", _) => Ok("darwin"),
+        ("windows", _) => Ok("windows"),
+        (os, arch) => Err(format!("unsupported platform: {}-{}", os, arch)),
+    }
+}
+
+fn bin_dir() -> PathBuf {
+    PathBuf::from(BIN_DIR)
+}
+
+fn binary_path(tool: &str) -> PathBuf {
+    let ext = if cfg!(windows) { ".exe" } else { "" };
+    bin_dir().join(format!("{}{}", tool, ext))
+}
+
+/// Downloads and installs a pinned version of `tool` into the crate-managed
+/// bin directory if it isn't already present there.
+pub async fn install(tool: &str) -> Result<PathBuf, String> {
+    let dest = binary_path(tool);
+    if dest.exists() {
+        return Ok(dest);
+    }
+
+    std::fs::create_dir_all(bin_dir()).map_err(|e| e.to_string())?;
+
+    let version = pinned_version(tool);
+    let platform = platform_tag()?;
+    let url = download_url(tool, &version, platform)?;
+
+    let archive_path = bin_dir().join(format!("{}-download", tool));
+    download_archive(&url, &archive_path).await?;
+
+    if let Some(expected) = known_checksum(tool, &version, platform) {
+        if let Err(e) = verify_checksum(&archive_path, expected) {
+            let _ = std::fs::remove_file(&archive_path);
+            return Err(format!("refusing to install {} {}: {}", tool, version, e));
+        }
+    }
+
+    extract_binary(&archive_path, tool, &dest)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(meta) = std::fs::metadata(&dest) {
+            let mut perms = meta.permissions();
+            perms.set_mode(0o755);
+            let _ = std::fs::set_permissions(&dest, perms);
+        }
+    }
+
+    let _ = std::fs::remove_file(&archive_path);
+    record_install(tool, &version);
+    Ok(dest)
+}
+
+fn download_url(tool: &str, version: &str, platform: &str) -> Result<String, String> {
+    let archive_ext = if platform == "windows" { "zip" } else { "tar.gz" };
+    match tool {
+        "geth" => Ok(format!(
+            "https://gethstore.blob.core.windows.net/builds/geth-{}-{}.{}",
+            platform, version, archive_ext
+        )),
+        "anvil" => Ok(format!(
+            "https://github.com/foundry-rs/foundry/releases/download/v{}/foundry_{}_{}_amd64.{}",
+            version, version, platform, archive_ext
+        )),
+        "solc" => Ok(format!(
+            "https://github.com/ethereum/solidity/releases/download/v{}/solc-static-{}",
+            version, platform
+        )),
+        other => Err(format!("no known download source for '{}'", other)),
+    }
+}
+
+async fn download_archive(url: &str, dest: &Path) -> Result<(), String> {
+    let response = reqwest::get(url).await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("download failed with status {}", response.status()));
+    }
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+    std::fs::write(dest, &bytes).map_err(|e| e.to_string())
+}
+
+/// Extracts the single binary we care about out of the downloaded archive.
+fn extract_binary(archive_path: &Path, tool: &str, dest: &Path) -> Result<(), String> {
+    if archive_path
+        .to_string_lossy()
+        .ends_with("solc")
+        || tool == "solc"
+    {
+        // solc ships as a single static binary, not an archive.
+        std::fs::copy(archive_path, dest).map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let tar_gz = std::fs::File::open(archive_path).map_err(|e| e.to_string())?;
+    let decoder = flate2::read::GzDecoder::new(tar_gz);
+    let mut archive = tar::Archive::new(decoder);
+    let extract_dir = bin_dir().join(format!("{}-extract", tool));
+    archive.unpack(&extract_dir).map_err(|e| e.to_string())?;
+
+    let found = find_binary(&extract_dir, tool)
+        .ok_or_else(|| format!("{} binary not found in downloaded archive", tool))?;
+    std::fs::rename(found, dest).map_err(|e| e.to_string())?;
+    let _ = std::fs::remove_dir_all(&extract_dir);
+    Ok(())
+}
+
+fn find_binary(dir: &Path, tool: &str) -> Option<PathBuf> {
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if let Some(found) = find_binary(&path, tool) {
+                return Some(found);
+            }
+        } else if path.file_stem().and_then(|s| s.to_str()) == Some(tool) {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Ensures `tool` is available, either on `PATH` or in the managed bin
+/// directory, installing it if necessary. Returns the resolved path and
+/// prepends the managed bin directory to the child process `PATH`.
+pub async fn ensure(tool: &str) -> Result<PathBuf, String> {
+    if let Some(path) = which_on_path(tool) {
+        return Ok(path);
+    }
+
+    let installed = install(tool).await?;
+    prepend_to_path();
+    Ok(installed)
+}
+
+/// Resolves `tool` to a concrete path without installing it, for `eth-bp dev
+/// toolchain which` -- `PATH` first, then the managed bin directory.
+pub fn resolve(tool: &str) -> Option<PathBuf> {
+    which_on_path(tool).or_else(|| {
+        let managed = binary_path(tool);
+        managed.exists().then_some(managed)
+    })
+}
+
+fn which_on_path(tool: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var).find_map(|dir| {
+        let candidate = dir.join(tool);
+        candidate.exists().then_some(candidate)
+    })
+}
+
+fn prepend_to_path() {
+    let managed = bin_dir();
+    let current = std::env::var_os("PATH").unwrap_or_default();
+    let mut paths: Vec<PathBuf> = vec![managed];
+    paths.extend(std::env::split_paths(&current));
+    if let Ok(joined) = std::env::join_paths(paths) {
+        std::env::set_var("PATH", joined);
+    }
+}
+
+/// A single row in the `doctor` report: what's expected vs. what's installed.
+pub struct ToolStatus {
+    pub tool: String,
+    pub expected_version: String,
+    pub installed: bool,
+    pub resolved_path: Option<String>,
+}
+
+/// Reports installed-vs-expected versions for every managed toolchain binary.
+pub async fn doctor() -> Vec<ToolStatus> {
+    let mut rows = Vec::new();
+    for tool in ["anvil", "geth", "solc"] {
+        let resolved = which_on_path(tool).or_else(|| {
+            let managed = binary_path(tool);
+            managed.exists().then_some(managed)
+        });
+        rows.push(ToolStatus {
+            tool: tool.to_string(),
+            expected_version: pinned_version(tool),
+            installed: resolved.is_some(),
+            resolved_path: resolved.map(|p| p.to_string_lossy().to_string()),
+        });
+    }
+    rows
+}
+
+/// Returns true if `tool --version` runs successfully, used before falling
+/// back to the installer.
+pub async fn is_on_path(tool: &str) -> bool {
+    Command::new(tool).arg("--version").output().await.is_ok()
+}
@@ -5,10 +5,12 @@ use clap::{Parser, Subcommand};
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::Path;
-use tracing::{info, error, warn};
+use tracing::{info, error, warn, debug};
 
 // Предполагаем наличие этих модулей в структуре проекта
 mod commands;
+mod migrations;
+mod term;
 mod utils;
 
 use ethereum_boilerplate_utils::{Config, init_logging, Result};
@@ -43,6 +45,14 @@ pub struct Cli {
     /// Target network (mainnet, sepolia, localhost, etc.)
     #[arg(short, long, default_value = "localhost", global = true)]
     network: String,
+
+    /// Control ANSI color/progress-bar output (auto follows the terminal + NO_COLOR)
+    #[arg(long, value_enum, default_value = "auto", global = true)]
+    color: term::ColorMode,
+
+    /// On failure, print `{ "kind", "code", "message" }` on stdout instead of a colored error
+    #[arg(long, global = true)]
+    json_errors: bool,
 }
 
 #[derive(Subcommand)]
@@ -61,6 +71,13 @@ enum Commands {
         port: u16,
         #[arg(short, long)]
         build: bool,
+        /// Hash the `--build` artifacts and publish them to the S3-compatible
+        /// bucket configured under `release` in config.json
+        #[arg(long)]
+        upload: bool,
+        /// Generate a Dockerfile and build a servable container image from the `--build` artifacts
+        #[arg(long)]
+        docker: bool,
     },
 
     /// 🚀 Smart contract deployment
@@ -71,6 +88,18 @@ enum Commands {
         private_key: Option<String>,
         #[arg(short, long)]
         yes: bool,
+        /// Gas pricing strategy for the EIP-1559 gas oracle (slow/standard/fast/urgent)
+        #[arg(long, default_value = "standard")]
+        gas_strategy: String,
+        /// Path to a multi-contract deploy plan (JSON); runs a simulate phase, then broadcasts
+        #[arg(long)]
+        plan: Option<String>,
+        /// Only simulate the plan (predicted addresses, gas, call traces); never broadcasts
+        #[arg(long)]
+        dry_run: bool,
+        /// Actually submit the deployment transactions for the plan
+        #[arg(long)]
+        broadcast: bool,
     },
 
     /// 🛠️ Dev utilities (Node, Wallet, Migration)
@@ -96,13 +125,109 @@ enum Commands {
         #[command(subcommand)]
         action: WalletSubcommands,
     },
+
+    /// 📒 List contracts recorded in the persistent deployment registry
+    Contracts {
+        /// Filter to a single network name
+        #[arg(short, long)]
+        network: Option<String>,
+    },
+
+    /// 📡 Long-running JSON-RPC server (get_balance, deploy_contract, mint_tokens, transfer_tokens, transaction_history)
+    Rpc {
+        #[arg(short, long, default_value_t = 3000)]
+        port: u16,
+    },
+
+    /// 🔧 Build backend/frontend, or cross-compile a release target matrix
+    Build {
+        #[arg(short, long)]
+        release: bool,
+        #[arg(long)]
+        skip_frontend: bool,
+        #[arg(long)]
+        skip_backend: bool,
+        #[arg(short, long)]
+        target: Option<String>,
+        #[arg(short, long)]
+        package: Option<String>,
+        /// Cross-compile release binaries for the full target matrix (linux x86_64/armv7/arm/aarch64, windows-msvc) instead of a single --target
+        #[arg(long)]
+        release_matrix: bool,
+        /// Restrict --release-matrix to these comma-separated target triples instead of the full default matrix
+        #[arg(long, value_delimiter = ',')]
+        matrix_targets: Option<Vec<String>>,
+    },
+
+    /// 🧪 Run unit/integration/WASM test suites
+    Test {
+        #[arg(short, long, default_value = "all")]
+        test_type: String,
+        #[arg(short, long)]
+        release: bool,
+        #[arg(short, long)]
+        verbose: bool,
+        /// WASM test browser (chrome/firefox/safari/node); auto-detected from installed drivers when omitted
+        #[arg(long)]
+        browser: Option<String>,
+        /// Skip WASM tests entirely, even if wasm-pack and a driver are available
+        #[arg(long)]
+        no_wasm: bool,
+        /// Write a JUnit (.xml) or JSON (.json) test report to this path
+        #[arg(long)]
+        report: Option<String>,
+    },
+
+    /// 📊 Run declarative JSON workload files against the running dev/server stack
+    Bench {
+        /// One or more workload JSON file paths
+        #[arg(short, long = "workload", required = true)]
+        workloads: Vec<String>,
+        /// POST the aggregated results to `bench_results_url` from the config
+        #[arg(long)]
+        upload: bool,
+    },
 }
 
 #[derive(Subcommand)]
 enum DevSubcommands {
     GenerateWallet,
     RunNode { #[arg(short, long, default_value_t = 8545)] port: u16 },
-    Migrate { #[arg(short, long)] rollback: bool },
+    Migrate {
+        #[arg(short, long)] rollback: bool,
+        /// Number of migrations to roll back (only meaningful with `--rollback`)
+        #[arg(long, default_value_t = 1)] steps: u32,
+    },
+    /// Report installed-vs-expected versions of the managed dev toolchain
+    Doctor,
+    /// Manage the pinned geth/anvil/solc installs `run-node` resolves against (`deploy` does not use them)
+    Toolchain {
+        #[command(subcommand)]
+        action: ToolchainSubcommands,
+    },
+    /// API analytics over the `api_logs` table: latency percentiles, request
+    /// volume by hour, and the most-queried addresses
+    Stats {
+        /// How far back to look, e.g. "24h", "7d", "30m" (default: 24h)
+        #[arg(long)]
+        since: Option<String>,
+        /// Number of top addresses to show
+        #[arg(long, default_value_t = 10)]
+        limit: i64,
+        /// Emit JSON instead of colored tables
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ToolchainSubcommands {
+    /// Download and verify the pinned version of a tool (or all of them, if omitted)
+    Install { tool: Option<String> },
+    /// Show installed-vs-expected versions, same rows as `dev doctor`
+    List,
+    /// Print the resolved path for a tool, for debugging PATH/install issues
+    Which { tool: String },
 }
 
 #[derive(Subcommand)]
@@ -115,7 +240,11 @@ pub enum ConfigSubcommands {
 
 #[derive(Subcommand)]
 pub enum NetworkSubcommands {
-    Status,
+    Status {
+        /// Emit the node health snapshot as JSON instead of a colored table
+        #[arg(long)]
+        json: bool,
+    },
     GasPrice,
     BlockNumber,
     Switch { network: String },
@@ -132,6 +261,20 @@ pub enum WalletSubcommands {
         #[arg(short, long)]
         nonce: bool,
     },
+
+    /// Derive the address from a Ledger device instead of a raw private
+    /// key, so signing key material never has to be typed into this
+    /// process. Trezor devices are not supported.
+    Hardware {
+        /// BIP-44 account index (`m/44'/60'/0'/0/{index}`). Prompts with a
+        /// short list of candidate accounts to choose from when omitted.
+        #[arg(short, long)]
+        account_index: Option<u32>,
+        #[arg(short, long)]
+        balance: bool,
+        #[arg(short, long)]
+        nonce: bool,
+    },
 }
 
 // --- Трейт для улучшения взаимодействия с пользователем ---
@@ -160,6 +303,10 @@ impl crate::utils::Messenger for Cli {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    // 0. Terminal capabilities: must run before any output so a redirected
+    // run (file, CI log, pipe) stays free of escape codes.
+    term::init(cli.color);
+
     // 1. Logging
     let log_level = if cli.debug { "debug" } else { "info" };
     init_logging(log_level).map_err(|e| {
@@ -175,9 +322,20 @@ async fn main() -> Result<()> {
     info!("Network: {}", config.network.name.cyan());
 
     // 4. Execution
+    let json_errors = cli.json_errors;
     if let Err(e) = execute(cli, config).await {
         error!("Execution error: {}", e);
-        std::process::exit(1);
+        // The full cause chain (and backtrace, if RUST_BACKTRACE is set) is
+        // too verbose for the default error line above; it's still there
+        // for anyone running with --debug.
+        debug!("{}", e.detailed());
+        let format = if json_errors {
+            ethereum_boilerplate_utils::error::ReportFormat::Json
+        } else {
+            ethereum_boilerplate_utils::error::ReportFormat::Pretty
+        };
+        e.report(format);
+        std::process::exit(e.exit_code());
     }
 
     Ok(())
@@ -188,20 +346,50 @@ async fn execute(cli: Cli, config: Config) -> Result<()> {
         Commands::Server { port, watch } => {
             commands::server::run_server(config, port, watch).await
         }
-        Commands::Frontend { port, build } => {
-            commands::frontend::run_frontend(config, port, build).await
+        Commands::Frontend { port, build, upload, docker } => {
+            commands::frontend::run_frontend(config, port, build, upload, docker).await
         }
-        Commands::Deploy { contract_type, private_key, yes } => {
-            commands::deploy::deploy_contracts(config, contract_type, private_key, None, yes).await
+        Commands::Deploy { contract_type, private_key, yes, gas_strategy, plan, dry_run, broadcast } => {
+            if let Some(plan_path) = plan {
+                let deployed = commands::deploy::run_deploy_plan(config, plan_path, private_key, dry_run, broadcast, yes).await?;
+                for contract in &deployed {
+                    println!(
+                        "{:#x} tx={}",
+                        contract.address,
+                        contract.transaction_hash.map(|h| format!("{:#x}", h)).unwrap_or_else(|| "pending".to_string())
+                    );
+                }
+                Ok(())
+            } else {
+                commands::deploy::deploy_contracts(config, contract_type, private_key, None, yes, gas_strategy).await
+            }
         }
         Commands::Dev { ref action } => match action {
             DevSubcommands::GenerateWallet => commands::dev::generate_wallet(&cli).await,
             DevSubcommands::RunNode { port } => commands::dev::run_local_node(&cli, *port, 0).await,
-            DevSubcommands::Migrate { rollback } => commands::dev::migrate_database(&cli, config, *rollback).await,
+            DevSubcommands::Migrate { rollback, steps } => commands::dev::migrate_database(&cli, config, *rollback, *steps).await,
+            DevSubcommands::Doctor => commands::dev::doctor(&cli).await,
+            DevSubcommands::Toolchain { action } => commands::dev::handle_toolchain_command(&cli, action).await,
+            DevSubcommands::Stats { since, limit, json } => {
+                commands::dev::show_api_stats(&cli, config, since.clone(), *limit, *json).await
+            }
         },
         Commands::Config { action } => commands::config::handle_config_command(config, action).await,
         Commands::Network { action } => commands::network::handle_network_command(config, action).await,
         Commands::Wallet { action } => commands::wallet::handle_wallet_command(config, action).await,
+        Commands::Contracts { network } => commands::deploy::list_contracts(network).await,
+        Commands::Build { release, skip_frontend, skip_backend, target, package, release_matrix, matrix_targets } => {
+            if release_matrix {
+                commands::build::build_release_matrix(matrix_targets).await
+            } else {
+                commands::build::build_project(config, release, skip_frontend, skip_backend, target, package).await
+            }
+        }
+        Commands::Rpc { port } => commands::rpc::run_rpc_server(config, port).await,
+        Commands::Test { test_type, release, verbose, browser, no_wasm, report } => {
+            commands::test::run_tests(config, test_type, release, verbose, browser, no_wasm, report).await
+        }
+        Commands::Bench { workloads, upload } => commands::bench::run_bench(config, workloads, upload).await,
     }
 }
 
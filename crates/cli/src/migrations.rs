@@ -0,0 +1,268 @@
+//! Reversible SQL migration engine driven directly against a `sqlx`
+//! `PgPool`, the same `schema_migrations`-tracked design as the server's
+//! `DatabaseService` (`crates/server/src/database.rs`). Duplicated here
+//! rather than imported because `ethereum_boilerplate_server` is a binary
+//! crate with no library target for the CLI to depend on.
+
+use chrono::{DateTime, Utc};
+use ethereum_boilerplate_utils::{Result, UtilsError};
+use sha2::{Digest, Sha256};
+use sqlx::{postgres::PgPoolOptions, Pool, Postgres, Row};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+type DbPool = Pool<Postgres>;
+
+async fn connect(db_url: &str) -> Result<DbPool> {
+    PgPoolOptions::new()
+        .max_connections(5)
+        .connect(db_url)
+        .await
+        .map_err(|e| UtilsError::database(format!("connecting to '{}': {}", db_url, e)))
+}
+
+/// Creates `schema_migrations` if it doesn't already exist.
+async fn ensure_schema_migrations_table(pool: &DbPool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version BIGINT PRIMARY KEY,
+            name TEXT NOT NULL,
+            checksum TEXT NOT NULL,
+            applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| UtilsError::database(e.to_string()))?;
+
+    Ok(())
+}
+
+struct AppliedMigration {
+    name: String,
+    checksum: String,
+    applied_at: DateTime<Utc>,
+}
+
+/// Every row currently recorded in `schema_migrations`, keyed by version.
+async fn applied_migrations(pool: &DbPool) -> Result<BTreeMap<i64, AppliedMigration>> {
+    ensure_schema_migrations_table(pool).await?;
+
+    let rows = sqlx::query("SELECT version, name, checksum, applied_at FROM schema_migrations ORDER BY version")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| UtilsError::database(e.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let version: i64 = row.get("version");
+            (
+                version,
+                AppliedMigration {
+                    name: row.get("name"),
+                    checksum: row.get("checksum"),
+                    applied_at: row.get("applied_at"),
+                },
+            )
+        })
+        .collect())
+}
+
+/// One discovered migration: a paired `NNNN_name.up.sql`/`NNNN_name.down.sql`
+/// file on disk. `checksum` is the up-file's SHA-256, compared against what
+/// was recorded at apply time to catch an edited migration after the fact.
+#[derive(Debug, Clone)]
+struct Migration {
+    version: i64,
+    name: String,
+    up_sql: String,
+    down_sql: String,
+    checksum: String,
+}
+
+/// Applied-vs-pending row returned by [`migration_status`].
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub name: String,
+    pub applied: bool,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub checksum_mismatch: bool,
+}
+
+#[derive(Default)]
+struct PartialMigration {
+    name: Option<String>,
+    up_sql: Option<String>,
+    down_sql: Option<String>,
+}
+
+/// Reads `dir` for `NNNN_name.up.sql`/`NNNN_name.down.sql` pairs, sorted by
+/// version. An `.up.sql` with no matching `.down.sql` (or vice versa) is a
+/// migration this engine can't safely track, so it's an error rather than a
+/// silently-forward-only migration.
+fn discover_migrations(dir: &Path) -> Result<Vec<Migration>> {
+    let mut partials: BTreeMap<i64, PartialMigration> = BTreeMap::new();
+
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| UtilsError::database(format!("reading {}: {}", dir.display(), e)))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| UtilsError::database(e.to_string()))?;
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        let (stem, is_up) = if let Some(stem) = file_name.strip_suffix(".up.sql") {
+            (stem, true)
+        } else if let Some(stem) = file_name.strip_suffix(".down.sql") {
+            (stem, false)
+        } else {
+            continue;
+        };
+
+        let Some((version_str, name)) = stem.split_once('_') else {
+            return Err(UtilsError::database(format!(
+                "malformed migration filename (expected NNNN_name.{{up,down}}.sql): {}",
+                file_name
+            )));
+        };
+        let version: i64 = version_str
+            .parse()
+            .map_err(|_| UtilsError::database(format!("malformed migration version in filename: {}", file_name)))?;
+
+        let sql = std::fs::read_to_string(&path)
+            .map_err(|e| UtilsError::database(format!("reading {}: {}", path.display(), e)))?;
+
+        let partial = partials.entry(version).or_default();
+        partial.name = Some(name.to_string());
+        if is_up {
+            partial.up_sql = Some(sql);
+        } else {
+            partial.down_sql = Some(sql);
+        }
+    }
+
+    partials
+        .into_iter()
+        .map(|(version, partial)| {
+            let name = partial.name.unwrap_or_default();
+            let up_sql = partial.up_sql.ok_or_else(|| {
+                UtilsError::database(format!("migration {:04}_{} has a down.sql but no matching up.sql", version, name))
+            })?;
+            let down_sql = partial.down_sql.ok_or_else(|| {
+                UtilsError::database(format!("migration {:04}_{} has no matching down.sql", version, name))
+            })?;
+            let checksum = format!("{:x}", Sha256::digest(up_sql.as_bytes()));
+
+            Ok(Migration { version, name, up_sql, down_sql, checksum })
+        })
+        .collect()
+}
+
+/// Applies every discovered migration whose version isn't recorded in
+/// `schema_migrations` yet, in version order, each inside its own transaction followed by its
+/// `schema_migrations` insert, so a failing migration leaves every earlier
+/// one committed and recorded. Returns the versions that were applied.
+pub async fn apply_pending_migrations(db_url: &str, dir: &Path) -> Result<Vec<i64>> {
+    let pool = connect(db_url).await?;
+    let migrations = discover_migrations(dir)?;
+    let applied = applied_migrations(&pool).await?;
+
+    let mut applied_versions = Vec::new();
+
+    for migration in migrations.into_iter().filter(|m| !applied.contains_key(&m.version)) {
+        let mut tx = pool.begin().await.map_err(|e| UtilsError::database(e.to_string()))?;
+
+        sqlx::query(&migration.up_sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| UtilsError::database(e.to_string()))?;
+
+        sqlx::query("INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES ($1, $2, $3, NOW())")
+            .bind(migration.version)
+            .bind(&migration.name)
+            .bind(&migration.checksum)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| UtilsError::database(e.to_string()))?;
+
+        tx.commit().await.map_err(|e| UtilsError::database(e.to_string()))?;
+        applied_versions.push(migration.version);
+    }
+
+    Ok(applied_versions)
+}
+
+/// Rolls back the `steps` most recently applied migrations (by version,
+/// newest first), running every `.down.sql` and its `schema_migrations`
+/// delete inside one transaction so a failed rollback leaves the recorded
+/// history exactly as it was before the call. Returns the versions that
+/// were reverted, in the order they were reverted.
+pub async fn rollback(db_url: &str, dir: &Path, steps: usize) -> Result<Vec<i64>> {
+    if steps == 0 {
+        return Ok(Vec::new());
+    }
+
+    let pool = connect(db_url).await?;
+    let migrations = discover_migrations(dir)?
+        .into_iter()
+        .map(|m| (m.version, m))
+        .collect::<BTreeMap<_, _>>();
+    let applied = applied_migrations(&pool).await?;
+
+    let to_revert: Vec<i64> = applied.keys().rev().take(steps).copied().collect();
+    let mut reverted = Vec::new();
+    let mut tx = pool.begin().await.map_err(|e| UtilsError::database(e.to_string()))?;
+
+    for version in to_revert {
+        let migration = migrations.get(&version).ok_or_else(|| {
+            UtilsError::database(format!(
+                "applied migration {} has no migration files on disk -- cannot roll back",
+                version
+            ))
+        })?;
+
+        sqlx::query(&migration.down_sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| UtilsError::database(e.to_string()))?;
+        sqlx::query("DELETE FROM schema_migrations WHERE version = $1")
+            .bind(version)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| UtilsError::database(e.to_string()))?;
+
+        reverted.push(version);
+    }
+
+    tx.commit().await.map_err(|e| UtilsError::database(e.to_string()))?;
+    Ok(reverted)
+}
+
+/// Applied-vs-pending view: every discovered migration, flagged with
+/// whether it's applied and whether its on-disk checksum still matches what
+/// was recorded when it ran.
+pub async fn migration_status(db_url: &str, dir: &Path) -> Result<Vec<MigrationStatus>> {
+    let pool = connect(db_url).await?;
+    let migrations = discover_migrations(dir)?;
+    let applied = applied_migrations(&pool).await?;
+
+    Ok(migrations
+        .into_iter()
+        .map(|migration| {
+            let recorded = applied.get(&migration.version);
+            MigrationStatus {
+                version: migration.version,
+                name: migration.name,
+                applied: recorded.is_some(),
+                applied_at: recorded.map(|r| r.applied_at),
+                checksum_mismatch: recorded.is_some_and(|r| r.checksum != migration.checksum),
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,64 @@
+//! Terminal capability detection
+//!
+//! Decides whether ANSI color codes and progress-bar redraws are safe to
+//! emit, so piping `eth-bp` into a file, a log aggregator, or a CI job
+//! doesn't leave the output full of escape codes and `\r` spinner frames.
+
+use clap::ValueEnum;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+/// How `eth-bp` decides whether to emit ANSI color codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorMode {
+    /// Colorize only when stdout/stderr are a tty and `NO_COLOR` isn't set
+    Auto,
+    /// Always colorize, even when redirected
+    Always,
+    /// Never colorize
+    Never,
+}
+
+static COLOR_ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Resolves `mode` against the terminal/environment and installs the
+/// decision globally: `colored` stops emitting escape codes and
+/// [`progress_draw_target`] switches to a hidden target. Only the first
+/// call in a process wins; call this before any output is produced.
+pub fn init(mode: ColorMode) {
+    let enabled = resolve(mode);
+    colored::control::set_override(enabled);
+    let _ = COLOR_ENABLED.set(enabled);
+}
+
+fn resolve(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            if std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+                true
+            } else if std::env::var_os("NO_COLOR").is_some() {
+                false
+            } else {
+                std::io::stdout().is_terminal() && std::io::stderr().is_terminal()
+            }
+        }
+    }
+}
+
+/// Whether [`init`] decided colors/animations are on. Defaults to `true`
+/// if `init` hasn't run yet, matching `colored`'s own default.
+pub fn colors_enabled() -> bool {
+    *COLOR_ENABLED.get().unwrap_or(&true)
+}
+
+/// Draw target for progress bars/spinners: hidden when colors are off, so
+/// a redirected run doesn't get a file full of carriage-return frames.
+pub fn progress_draw_target() -> indicatif::ProgressDrawTarget {
+    if colors_enabled() {
+        indicatif::ProgressDrawTarget::stderr()
+    } else {
+        indicatif::ProgressDrawTarget::hidden()
+    }
+}
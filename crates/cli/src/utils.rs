@@ -49,6 +49,7 @@ pub fn print_error(msg: &str) {
 /// Создает спиннер для асинхронных задач (например, деплой)
 pub fn create_spinner(msg: impl Into<String>) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
+    pb.set_draw_target(crate::term::progress_draw_target());
     pb.set_style(
         ProgressStyle::default_spinner()
             .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
@@ -63,6 +64,7 @@ pub fn create_spinner(msg: impl Into<String>) -> ProgressBar {
 /// Создает классический progress bar для шаговых задач (например, установка зависимостей)
 pub fn create_progress_bar(total: u64) -> ProgressBar {
     let pb = ProgressBar::new(total);
+    pb.set_draw_target(crate::term::progress_draw_target());
     pb.set_style(
         ProgressStyle::default_bar()
             .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
@@ -1,6 +1,13 @@
 use leptos::*;
 use serde::{Deserialize, Serialize};
 
+use crate::middleware::{default_stack, Middleware, TxRequest};
+use crate::jsonrpc::JsonRpcClient;
+
+/// `totalSupply()` and `decimals()` ERC-20 function selectors.
+const SELECTOR_TOTAL_SUPPLY: &str = "0x18160ddd";
+const SELECTOR_DECIMALS: &str = "0x313ce567";
+
 // --- Data Models ---
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -24,21 +31,46 @@ pub struct NFTContractInfo {
 // --- Mock APIs (wallet/node interaction simulation) ---
 
 async fn get_contract_info(address: String) -> Result<ContractInfo, String> {
-    if address.is_empty() { return Err("Invalid address".into()); }
+    if address.is_empty() {
+        return Err("Invalid address".into());
+    }
+
+    let client = JsonRpcClient::new("http://localhost:8545");
+    let total_supply_hex = client
+        .eth_call(&address, SELECTOR_TOTAL_SUPPLY)
+        .await
+        .map_err(|e| e.to_string())?;
+    let decimals_hex = client
+        .eth_call(&address, SELECTOR_DECIMALS)
+        .await
+        .map_err(|e| e.to_string())?;
+    let decimals = u64::from_str_radix(decimals_hex.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("invalid decimals() response: {}", e))? as u8;
+
     Ok(ContractInfo {
         name: "Mock Token".to_string(),
         symbol: "MTK".to_string(),
         address,
-        total_supply: "1,000,000".to_string(),
-        decimals: 18,
+        total_supply: total_supply_hex,
+        decimals,
     })
 }
 
-async fn mint_tokens(amount: String) -> Result<String, String> {
-    Ok(format!("Successfully minted {} tokens", amount))
+async fn mint_tokens(address: String, amount: String) -> Result<String, String> {
+    let stack = default_stack(address.clone(), "http://localhost:8545");
+    let tx = TxRequest {
+        to: address,
+        data: format!("mint({})", amount),
+        ..Default::default()
+    };
+    let hash = stack.send_transaction(tx).await?;
+    Ok(format!("Successfully minted {} tokens ({})", amount, hash))
 }
 
-async fn get_nft_contracts(_user_addr: String) -> Result<Vec<NFTContractInfo>, String> {
+async fn get_nft_contracts(user_addr: String) -> Result<Vec<NFTContractInfo>, String> {
+    let stack = default_stack(user_addr.clone(), "http://localhost:8545");
+    let tx = TxRequest { to: user_addr, data: "ownedCollections()".to_string(), ..Default::default() };
+    stack.call(&tx).await?;
     Ok(vec![
         NFTContractInfo {
             address: "0x123...".to_string(),
@@ -69,8 +101,9 @@ pub fn ContractInterface(address: String) -> impl IntoView {
     let handle_mint = move |_| {
         set_loading.set(true);
         let amount = mint_amount.get();
+        let address = addr.get_value();
         spawn_local(async move {
-            let res = mint_tokens(amount).await;
+            let res = mint_tokens(address, amount).await;
             set_status.set(Some(res.unwrap_or_else(|e| e)));
             set_loading.set(false);
         });
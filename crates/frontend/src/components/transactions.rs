@@ -1,3 +1,5 @@
+use crate::config::ExplorerConfig;
+use crate::explorer;
 use leptos::*;
 use serde::{Deserialize, Serialize};
 use std::rc::Rc;
@@ -317,41 +319,11 @@ pub fn RecentTransactions() -> impl IntoView {
     }
 }
 
-// API functions (mock implementations)
+// API functions
 async fn get_transactions(address: &str, page: u32) -> Result<Vec<Transaction>, String> {
-    // Mock implementation - in real app, this would call backend API
-    let mock_transactions = vec![
-        Transaction {
-            hash: "0x1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
-            from: address.to_string(),
-            to: Some("0x9876543210fedcba9876543210fedcba9876543210fedcba".to_string()),
-            value: "0.123456".to_string(),
-            gas_used: "21000".to_string(),
-            gas_price: Some("20".to_string()),
-            block_number: 18500000,
-            timestamp: Some("2024-01-15 10:30:00".to_string()),
-            status: TransactionStatus::Success,
-        },
-        Transaction {
-            hash: "0xabcdef1234567890abcdef1234567890abcdef1234567890".to_string(),
-            from: "0x9876543210fedcba9876543210fedcba9876543210fedcba".to_string(),
-            to: Some(address.to_string()),
-            value: "0.054321".to_string(),
-            gas_used: "21000".to_string(),
-            gas_price: Some("25".to_string()),
-            block_number: 18499950,
-            timestamp: Some("2024-01-15 09:45:00".to_string()),
-            status: TransactionStatus::Success,
-        },
-    ];
-
-    let start_index = ((page - 1) * 10) as usize;
-    let end_index = (start_index + 10).min(mock_transactions.len());
-    
-    Ok(mock_transactions[start_index..end_index].to_vec())
+    explorer::fetch_transactions(&ExplorerConfig::default(), address, page).await
 }
 
 async fn get_recent_transactions(address: &str) -> Result<Vec<Transaction>, String> {
-    // Mock implementation - return last 5 transactions
     get_transactions(address, 1).await
 }
@@ -2,13 +2,15 @@ use leptos::*;
 use leptos_router::*;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
-use web_sys::{console, window};
+use web_sys::console;
 use gloo_net::http::Request;
 use gloo_storage::{LocalStorage, Storage};
 use js_sys::Promise;
 use wasm_bindgen_futures::spawn_local;
 use std::rc::Rc;
 use ethereum_boilerplate_shared::{SUPPORTED_NETWORKS, NetworkInfo};
+use crate::walletconnect;
+use crate::jsonrpc::JsonRpcClient;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WalletInfo {
@@ -23,26 +25,38 @@ pub fn WalletConnector() -> impl IntoView {
         .expect("wallet_connected context provided");
     let (current_address, set_current_address) = use_context::<WriteSignal<String>>()
         .expect("current_address context provided");
+    let (pairing_error, set_pairing_error) = create_signal(Option::<String>::None);
+
+    // Restore a previously-approved session silently on mount.
+    create_effect(move |_| {
+        if let Some(session) = walletconnect::restore_session() {
+            if let Some(address) = session.primary_account() {
+                set_wallet_connected.set(true);
+                set_current_address.set(address.to_string());
+            }
+        }
+    });
 
     let connect_wallet = move |_| {
-        spawn_local(async move {
-            match connect_to_wallet().await {
-                Ok(address) => {
-                    set_wallet_connected.set(true);
-                    set_current_address.set(address.clone());
-                    console::log_1(&format!("Wallet connected: {}", address));
+        walletconnect::spawn_pairing(60_000, move |result| {
+            match result {
+                Ok(session) => {
+                    if let Some(address) = session.primary_account() {
+                        set_wallet_connected.set(true);
+                        set_current_address.set(address.to_string());
+                        console::log_1(&format!("WalletConnect session approved: {}", address));
+                    }
                 }
                 Err(e) => {
-                    console::error_1(&format!("Failed to connect wallet: {}", e));
+                    console::error_1(&format!("WalletConnect pairing failed: {}", e));
+                    set_pairing_error.set(Some(e.to_string()));
                 }
             }
         });
     };
 
     let disconnect_wallet = move |_| {
-        let storage = LocalStorage::new();
-        storage.delete("wallet_address");
-        storage.delete("wallet_connected");
+        walletconnect::clear_session();
         set_wallet_connected.set(false);
         set_current_address.set(String::new());
         console::log_1("Wallet disconnected");
@@ -60,7 +74,7 @@ pub fn WalletConnector() -> impl IntoView {
                                 </span>
                                 <span class="connection-indicator connected">"ðŸŸ¢"</span>
                             </div>
-                            <button 
+                            <button
                                 class="disconnect-button"
                                 on:click=disconnect_wallet
                             >
@@ -68,9 +82,15 @@ pub fn WalletConnector() -> impl IntoView {
                             </button>
                         </div>
                     }
+                } else if let Some(error) = pairing_error.get() {
+                    view! {
+                        <div class="wallet-pairing-unavailable">
+                            <p>{error}</p>
+                        </div>
+                    }
                 } else {
                     view! {
-                        <button 
+                        <button
                             class="connect-button"
                             on:click=connect_wallet
                         >
@@ -144,29 +164,20 @@ pub fn WalletInfo() -> impl IntoView {
     }
 }
 
-// Wallet connection functions
-async fn connect_to_wallet() -> Result<String, String> {
-    // In a real implementation, this would connect to MetaMask or other wallets
-    // For now, we'll simulate with a mock implementation
-    
-    let window = window().ok_or("Failed to get window")?;
-    let storage = LocalStorage::new();
-    
-    // Simulate wallet connection
-    let mock_address = "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b";
-    
-    storage.set("wallet_address", mock_address);
-    storage.set("wallet_connected", "true");
-    
-    Ok(mock_address.to_string())
-}
-
 async fn get_wallet_info(address: &str) -> Result<WalletInfo, String> {
-    // Simulate API call to get wallet info
+    let client = JsonRpcClient::new("http://localhost:8545");
+    let balance_wei = client
+        .eth_get_balance(address)
+        .await
+        .map_err(|e| e.to_string())?;
+    let chain_id = client.eth_chain_id().await.map_err(|e| e.to_string())?;
+    let balance = u128::from_str_radix(balance_wei.trim_start_matches("0x"), 16)
+        .map(|wei| format!("{:.6}", wei as f64 / 1e18))
+        .map_err(|e| format!("eth_getBalance returned an invalid quantity: {}", e))?;
     Ok(WalletInfo {
         address: address.to_string(),
-        chain_id: 1,
-        balance: "1.2345".to_string(),
+        chain_id,
+        balance,
     })
 }
 
@@ -0,0 +1,42 @@
+//! Frontend-side configuration for external data sources.
+//!
+//! The WASM frontend has no filesystem/env access the way the CLI and
+//! server crates do, so these are compile-time defaults rather than a
+//! `Config::from_file`/`from_env` pair — override the `Default` impls below for a
+//! given deployment.
+
+/// Etherscan-style block explorer client configuration.
+#[derive(Debug, Clone)]
+pub struct ExplorerConfig {
+    pub api_base: String,
+    pub api_key: Option<String>,
+    pub retry: RetryConfig,
+}
+
+/// Exponential backoff with full jitter, applied to transient failures.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_backoff_ms: 250,
+            max_backoff_ms: 8_000,
+        }
+    }
+}
+
+impl Default for ExplorerConfig {
+    fn default() -> Self {
+        Self {
+            api_base: "https://api.etherscan.io/api".to_string(),
+            api_key: None,
+            retry: RetryConfig::default(),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Etherscan-style explorer client for transaction history.
+//!
+//! Fetches a paginated transaction list for an address via the `txlist`
+//! action, wrapping every call in [`crate::retry::with_retry`] so transient
+//! failures (timeouts, 429s, 5xx, connection resets) are retried with
+//! backoff instead of surfacing immediately.
+
+use crate::components::transactions::{Transaction, TransactionStatus};
+use crate::config::ExplorerConfig;
+use crate::retry::{classify_status, with_retry, ClassifiedError, ErrorClass};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+struct ExplorerResponse {
+    status: String,
+    message: String,
+    result: Value,
+}
+
+/// Fetches page `page` (1-indexed, 10 per page) of an address's
+/// transaction history.
+pub async fn fetch_transactions(config: &ExplorerConfig, address: &str, page: u32) -> Result<Vec<Transaction>, String> {
+    with_retry(&config.retry, || async {
+        let body = send_raw(&build_url(config, address, page)).await?;
+        parse_response(&body)
+    })
+    .await
+}
+
+fn build_url(config: &ExplorerConfig, address: &str, page: u32) -> String {
+    let mut url = format!(
+        "{}?module=account&action=txlist&address={}&page={}&offset=10&sort=desc",
+        config.api_base, address, page
+    );
+    if let Some(api_key) = &config.api_key {
+        url.push_str(&format!("&apikey={}", api_key));
+    }
+    url
+}
+
+fn parse_response(body: &str) -> Result<Vec<Transaction>, ClassifiedError> {
+    let parsed: ExplorerResponse =
+        serde_json::from_str(body).map_err(|e| ClassifiedError::fatal(format!("Decode error: {}", e)))?;
+
+    if parsed.status != "1" {
+        let message = parsed.message.to_lowercase();
+        if message.contains("no transactions found") {
+            return Ok(Vec::new());
+        }
+        if message.contains("rate limit") {
+            return Err(ClassifiedError::transient(parsed.message));
+        }
+        return Err(ClassifiedError::fatal(parsed.message));
+    }
+
+    let entries = parsed.result.as_array().cloned().unwrap_or_default();
+    Ok(entries.iter().filter_map(map_entry).collect())
+}
+
+fn map_entry(entry: &Value) -> Option<Transaction> {
+    let hash = entry.get("hash")?.as_str()?.to_string();
+    let from = entry.get("from")?.as_str()?.to_string();
+    let to = entry
+        .get("to")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+    let value = wei_to_eth_string(entry.get("value").and_then(|v| v.as_str()).unwrap_or("0"));
+    let gas_used = entry.get("gasUsed").and_then(|v| v.as_str()).unwrap_or("0").to_string();
+    let gas_price = entry.get("gasPrice").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let block_number = entry
+        .get("blockNumber")
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+    let timestamp = entry.get("timeStamp").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let status = match entry.get("isError").and_then(|v| v.as_str()) {
+        Some("1") => TransactionStatus::Failed,
+        _ => TransactionStatus::Success,
+    };
+
+    Some(Transaction {
+        hash,
+        from,
+        to,
+        value,
+        gas_used,
+        gas_price,
+        block_number,
+        timestamp,
+        status,
+    })
+}
+
+fn wei_to_eth_string(wei: &str) -> String {
+    wei.parse::<u128>()
+        .map(|w| format!("{:.6}", w as f64 / 1e18))
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn send_raw(url: &str) -> Result<String, ClassifiedError> {
+    let response = gloo_net::http::Request::get(url)
+        .send()
+        .await
+        .map_err(|e| ClassifiedError::transient(format!("Transport error: {}", e)))?;
+
+    let status = response.status();
+    if status != 200 {
+        return Err(status_error(status));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| ClassifiedError::fatal(format!("Decode error: {}", e)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_raw(url: &str) -> Result<String, ClassifiedError> {
+    let response = reqwest::get(url).await.map_err(|e| {
+        if e.is_timeout() || e.is_connect() {
+            ClassifiedError::transient(format!("Transport error: {}", e))
+        } else {
+            ClassifiedError::fatal(format!("Transport error: {}", e))
+        }
+    })?;
+
+    let status = response.status().as_u16();
+    if status != 200 {
+        return Err(status_error(status));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| ClassifiedError::fatal(format!("Decode error: {}", e)))
+}
+
+fn status_error(status: u16) -> ClassifiedError {
+    match classify_status(status) {
+        ErrorClass::Transient => ClassifiedError::transient(format!("HTTP {}", status)),
+        ErrorClass::Fatal => ClassifiedError::fatal(format!("HTTP {}", status)),
+    }
+}
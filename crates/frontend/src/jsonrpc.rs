@@ -0,0 +1,164 @@
+//! Typed JSON-RPC client for talking to a real Ethereum node.
+//!
+//! Replaces the hand-rolled mocks that used to stand in for node calls:
+//! each method here serializes its params into a
+//! `{"jsonrpc":"2.0","id":..,"method":..,"params":[..]}` envelope and
+//! deserializes the `result`/`error` fields back out, surfacing RPC
+//! `error` objects (code + message + optional `data`) distinctly from
+//! transport failures. Uses `gloo_net::http::Request` on `wasm32` (the
+//! browser build) and `reqwest` on native (Leptos SSR).
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// An error returned by the node itself, as opposed to a transport/network
+/// failure reaching it.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The node accepted the request but returned a JSON-RPC error object.
+    Rpc(RpcError),
+    /// The request never made it to/from the node (network, serialization).
+    Transport(String),
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Rpc(e) => write!(f, "RPC error {}: {}", e.code, e.message),
+            ClientError::Transport(msg) => write!(f, "transport error: {}", msg),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest<'a> {
+    jsonrpc: &'a str,
+    id: u64,
+    method: &'a str,
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+/// A typed client bound to a single RPC endpoint.
+#[derive(Clone, Debug)]
+pub struct JsonRpcClient {
+    endpoint: String,
+}
+
+impl JsonRpcClient {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self { endpoint: endpoint.into() }
+    }
+
+    async fn send(&self, method: &str, params: Value) -> Result<Value, ClientError> {
+        let body = JsonRpcRequest { jsonrpc: "2.0", id: 1, method, params };
+        let body = serde_json::to_string(&body)
+            .map_err(|e| ClientError::Transport(e.to_string()))?;
+
+        let text = send_raw(&self.endpoint, body).await?;
+
+        let response: JsonRpcResponse =
+            serde_json::from_str(&text).map_err(|e| ClientError::Transport(e.to_string()))?;
+
+        if let Some(error) = response.error {
+            return Err(ClientError::Rpc(error));
+        }
+        response
+            .result
+            .ok_or_else(|| ClientError::Transport("RPC response had neither result nor error".into()))
+    }
+
+    pub async fn eth_chain_id(&self) -> Result<u64, ClientError> {
+        let result = self.send("eth_chainId", json!([])).await?;
+        parse_hex_u64(&result)
+    }
+
+    pub async fn eth_get_balance(&self, address: &str) -> Result<String, ClientError> {
+        let result = self.send("eth_getBalance", json!([address, "latest"])).await?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| ClientError::Transport("eth_getBalance: expected a hex string".into()))
+    }
+
+    pub async fn eth_get_transaction_count(&self, address: &str) -> Result<u64, ClientError> {
+        let result = self
+            .send("eth_getTransactionCount", json!([address, "pending"]))
+            .await?;
+        parse_hex_u64(&result)
+    }
+
+    pub async fn eth_call(&self, to: &str, data: &str) -> Result<String, ClientError> {
+        let result = self
+            .send("eth_call", json!([{ "to": to, "data": data }, "latest"]))
+            .await?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| ClientError::Transport("eth_call: expected a hex string".into()))
+    }
+
+    pub async fn eth_send_raw_transaction(&self, raw_tx: &str) -> Result<String, ClientError> {
+        let result = self.send("eth_sendRawTransaction", json!([raw_tx])).await?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| ClientError::Transport("eth_sendRawTransaction: expected a tx hash".into()))
+    }
+
+    pub async fn eth_get_logs(&self, filter: Value) -> Result<Vec<Value>, ClientError> {
+        let result = self.send("eth_getLogs", json!([filter])).await?;
+        result
+            .as_array()
+            .cloned()
+            .ok_or_else(|| ClientError::Transport("eth_getLogs: expected an array".into()))
+    }
+}
+
+fn parse_hex_u64(value: &Value) -> Result<u64, ClientError> {
+    let hex = value
+        .as_str()
+        .ok_or_else(|| ClientError::Transport("expected a hex-encoded quantity".into()))?;
+    u64::from_str_radix(hex.trim_start_matches("0x"), 16)
+        .map_err(|e| ClientError::Transport(format!("invalid hex quantity {}: {}", hex, e)))
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn send_raw(endpoint: &str, body: String) -> Result<String, ClientError> {
+    gloo_net::http::Request::post(endpoint)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .map_err(|e| ClientError::Transport(e.to_string()))?
+        .send()
+        .await
+        .map_err(|e| ClientError::Transport(e.to_string()))?
+        .text()
+        .await
+        .map_err(|e| ClientError::Transport(e.to_string()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_raw(endpoint: &str, body: String) -> Result<String, ClientError> {
+    reqwest::Client::new()
+        .post(endpoint)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| ClientError::Transport(e.to_string()))?
+        .text()
+        .await
+        .map_err(|e| ClientError::Transport(e.to_string()))
+}
@@ -1,5 +1,13 @@
 use wasm_bindgen::prelude::*;
 
+pub mod components;
+pub mod config;
+pub mod explorer;
+pub mod jsonrpc;
+pub mod middleware;
+pub mod retry;
+pub mod walletconnect;
+
 #[wasm_bindgen]
 pub fn greet() -> String {
     "Hello, Ethereum Boilerplate!".to_string()
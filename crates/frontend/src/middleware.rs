@@ -0,0 +1,177 @@
+//! Composable provider middleware for the frontend's contract/wallet calls.
+//!
+//! Mirrors the layering pattern used by
+//! `ethereum_boilerplate_smart_contracts::middleware` (nonce manager, then
+//! gas oracle, then a base provider), but sized for the frontend's
+//! JSON-RPC-shaped mock client. Each layer wraps an `Inner: Middleware` and
+//! overrides only the methods it augments, defaulting everything else to
+//! `self.inner()`, so stacks like `NonceManager<GasOracle<Provider>>`
+//! compose cleanly.
+
+use std::cell::Cell;
+
+/// An outgoing call or transaction request, in the shape a JSON-RPC
+/// `eth_call`/`eth_sendTransaction` would take.
+#[derive(Clone, Debug, Default)]
+pub struct TxRequest {
+    pub to: String,
+    pub data: String,
+    pub value: String,
+    pub nonce: Option<u64>,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+}
+
+/// A layer in the provider stack. The base layer (`Provider`) terminates
+/// the chain by setting `Inner = Self` and overriding every method
+/// directly; augmenting layers delegate to `self.inner()` by default.
+pub trait Middleware {
+    type Inner: Middleware;
+
+    fn inner(&self) -> &Self::Inner;
+
+    async fn call(&self, tx: &TxRequest) -> Result<String, String> {
+        self.inner().call(tx).await
+    }
+
+    async fn send_transaction(&self, tx: TxRequest) -> Result<String, String> {
+        self.inner().send_transaction(tx).await
+    }
+
+    async fn get_balance(&self, address: &str) -> Result<String, String> {
+        self.inner().get_balance(address).await
+    }
+}
+
+/// The base layer: talks to the JSON-RPC endpoint. There is no real relay
+/// wired up yet, so calls are mocked here the way the rest of the frontend
+/// mocks wallet/node interaction.
+pub struct Provider {
+    pub rpc_url: String,
+}
+
+impl Provider {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self { rpc_url: rpc_url.into() }
+    }
+}
+
+impl Middleware for Provider {
+    type Inner = Self;
+
+    fn inner(&self) -> &Self {
+        self
+    }
+
+    async fn call(&self, tx: &TxRequest) -> Result<String, String> {
+        if tx.to.is_empty() {
+            return Err("Invalid address".to_string());
+        }
+        Ok("0x".to_string())
+    }
+
+    async fn send_transaction(&self, tx: TxRequest) -> Result<String, String> {
+        if tx.to.is_empty() && tx.data.is_empty() {
+            return Err("Transaction has no recipient or calldata".to_string());
+        }
+        Ok(format!("0x{:064x}", tx.nonce.unwrap_or(0) + 1))
+    }
+
+    async fn get_balance(&self, _address: &str) -> Result<String, String> {
+        Ok("1.2345".to_string())
+    }
+}
+
+/// Auto-fills `nonce` on outgoing transactions from a locally cached count,
+/// advanced after each successful send and cleared after a failed one. The
+/// cache is filled by `fetch_transaction_count`, which does not query the
+/// node: it always returns 0.
+pub struct NonceManager<M> {
+    inner: M,
+    account: String,
+    cached: Cell<Option<u64>>,
+}
+
+impl<M: Middleware> NonceManager<M> {
+    pub fn new(inner: M, account: impl Into<String>) -> Self {
+        Self { inner, account: account.into(), cached: Cell::new(None) }
+    }
+
+    async fn next_nonce(&self) -> Result<u64, String> {
+        if let Some(n) = self.cached.get() {
+            return Ok(n);
+        }
+        let fetched = fetch_transaction_count(&self.account).await?;
+        self.cached.set(Some(fetched));
+        Ok(fetched)
+    }
+}
+
+impl<M: Middleware> Middleware for NonceManager<M> {
+    type Inner = M;
+
+    fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    async fn send_transaction(&self, mut tx: TxRequest) -> Result<String, String> {
+        if tx.nonce.is_none() {
+            tx.nonce = Some(self.next_nonce().await?);
+        }
+        match self.inner().send_transaction(tx).await {
+            Ok(hash) => {
+                self.cached.set(self.cached.get().map(|n| n + 1));
+                Ok(hash)
+            }
+            Err(e) => {
+                // The local count may be stale; force a refresh next time.
+                self.cached.set(None);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Populates `gas_price`/`max_fee_per_gas` on outgoing transactions before
+/// they're sent.
+pub struct GasOracle<M> {
+    inner: M,
+}
+
+impl<M: Middleware> GasOracle<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M: Middleware> Middleware for GasOracle<M> {
+    type Inner = M;
+
+    fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    async fn send_transaction(&self, mut tx: TxRequest) -> Result<String, String> {
+        if tx.max_fee_per_gas.is_none() {
+            tx.max_fee_per_gas = Some(current_gas_price().await?);
+        }
+        if tx.gas_price.is_none() {
+            tx.gas_price = tx.max_fee_per_gas.clone();
+        }
+        self.inner().send_transaction(tx).await
+    }
+}
+
+async fn fetch_transaction_count(_account: &str) -> Result<u64, String> {
+    Ok(0)
+}
+
+async fn current_gas_price() -> Result<String, String> {
+    Ok("20000000000".to_string())
+}
+
+/// Builds the default stack used by the mock contract/wallet APIs:
+/// `NonceManager<GasOracle<Provider>>`.
+pub fn default_stack(account: impl Into<String>, rpc_url: impl Into<String>) -> NonceManager<GasOracle<Provider>> {
+    NonceManager::new(GasOracle::new(Provider::new(rpc_url)), account)
+}
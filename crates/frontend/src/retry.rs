@@ -0,0 +1,74 @@
+//! Retry-with-backoff helper for transient network failures.
+//!
+//! Wraps a fallible async operation, retrying [`ErrorClass::Transient`]
+//! failures (timeouts, 429s, 5xx, connection resets) with exponential
+//! backoff and full jitter, and failing fast on anything classified
+//! [`ErrorClass::Fatal`] (other 4xx, decode errors).
+
+use crate::config::RetryConfig;
+use std::future::Future;
+
+/// Whether a failure is worth retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    Transient,
+    Fatal,
+}
+
+/// An operation failure tagged with how the retry loop should treat it.
+#[derive(Debug, Clone)]
+pub struct ClassifiedError {
+    pub class: ErrorClass,
+    pub message: String,
+}
+
+impl ClassifiedError {
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self { class: ErrorClass::Transient, message: message.into() }
+    }
+
+    pub fn fatal(message: impl Into<String>) -> Self {
+        Self { class: ErrorClass::Fatal, message: message.into() }
+    }
+}
+
+/// Classifies an HTTP status code: 429 and 5xx are transient, everything
+/// else is fatal.
+pub fn classify_status(status: u16) -> ErrorClass {
+    if status == 429 || status >= 500 {
+        ErrorClass::Transient
+    } else {
+        ErrorClass::Fatal
+    }
+}
+
+/// Runs `op`, retrying up to `config.max_retries` times on transient
+/// failures with exponential backoff (`base * 2^attempt`, capped at
+/// `max_backoff_ms`) plus full jitter, failing immediately on a fatal
+/// error or once retries are exhausted.
+pub async fn with_retry<T, F, Fut>(config: &RetryConfig, mut op: F) -> Result<T, String>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ClassifiedError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if err.class == ErrorClass::Transient && attempt < config.max_retries => {
+                let backoff_ms = (config.base_backoff_ms.saturating_mul(1u64 << attempt)).min(config.max_backoff_ms);
+                let jitter_ms = (js_sys::Math::random() * backoff_ms as f64) as u32;
+                gloo_timers::future::TimeoutFuture::new(jitter_ms).await;
+                attempt += 1;
+            }
+            Err(err) => {
+                web_sys::console::error_1(&format!(
+                    "Request failed after {} attempt(s): {}",
+                    attempt + 1,
+                    err.message
+                ).into());
+                return Err(err.message);
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! WalletConnect v2 session types, `LocalStorage` persistence, and a
+//! pairing flow.
+//!
+//! IMPORTANT: `PendingPairing` does not speak to a WalletConnect relay.
+//! There is no WebSocket connection to a relay endpoint, no HKDF key
+//! derivation, and no pairing/session JSON-RPC exchange -- `pairing_uri()`
+//! only renders a `wc:`-shaped URI from locally-generated bytes that is
+//! never transmitted anywhere. A real wallet scanning it has no relay
+//! session to approve. `ensure_session` reflects that honestly: it returns
+//! [`PairingError::RelayUnavailable`] immediately instead of polling for an
+//! approval that nothing can ever deliver. `WalletConnector` must not
+//! present the generated URI as a working QR code/pairing link until a real
+//! relay client lands here.
+//!
+//! NOT PRODUCT-COMPLETE: this module is an honest retreat from a
+//! WalletConnect pairing feature, not the feature itself -- wallet
+//! connection via WalletConnect still doesn't work for a single real user.
+//! Don't let this file's presence get the backlog item that asked for it
+//! marked done without product sign-off on shipping "connect wallet" as
+//! permanently unavailable (vs. prioritizing the real relay client).
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+
+const SESSION_STORAGE_KEY: &str = "wc_session";
+
+/// A persisted WalletConnect v2 session: the pairing topic, the symmetric
+/// key negotiated during pairing, and the accounts/chain the peer approved.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WcSession {
+    pub topic: String,
+    pub sym_key: String,
+    pub accounts: Vec<String>,
+    pub chain_id: u64,
+}
+
+impl WcSession {
+    /// The first `eip155` account in the session, if any (the address the
+    /// rest of the UI treats as "the" connected account).
+    pub fn primary_account(&self) -> Option<&str> {
+        self.accounts.first().map(String::as_str)
+    }
+}
+
+/// An in-progress pairing: not yet approved by the peer wallet.
+pub struct PendingPairing {
+    topic: String,
+    sym_key: String,
+}
+
+impl PendingPairing {
+    /// Creates a new pairing request with a fresh topic/key pair, the way a
+    /// dApp initiating a WalletConnect v2 connection would.
+    pub fn new() -> Self {
+        Self {
+            topic: random_hex(32),
+            sym_key: random_hex(32),
+        }
+    }
+
+    /// The `wc:` URI to render as a QR code or copyable link.
+    pub fn pairing_uri(&self) -> String {
+        format!(
+            "wc:{}@2?relay-protocol=irn&symKey={}",
+            self.topic, self.sym_key
+        )
+    }
+
+    /// There is no relay connection for a peer to approve this pairing
+    /// over, so this cannot ever resolve with a real session. It returns
+    /// [`PairingError::RelayUnavailable`] immediately rather than polling
+    /// `LocalStorage` until `timeout_ms` elapses and pretending a real
+    /// attempt was made.
+    pub async fn ensure_session(self, _timeout_ms: u32) -> Result<WcSession, PairingError> {
+        Err(PairingError::RelayUnavailable)
+    }
+}
+
+/// Why a pairing attempt didn't produce a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairingError {
+    /// This build has no WalletConnect relay client -- there is nothing a
+    /// peer wallet could connect to, so pairing can never complete.
+    RelayUnavailable,
+}
+
+impl std::fmt::Display for PairingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RelayUnavailable => write!(
+                f,
+                "WalletConnect relay support isn't implemented in this build yet"
+            ),
+        }
+    }
+}
+
+/// Restores a previously-approved session from `LocalStorage`, if any, so
+/// reconnects on page load are silent.
+pub fn restore_session() -> Option<WcSession> {
+    LocalStorage::get(SESSION_STORAGE_KEY).ok()
+}
+
+/// Persists an approved session under the `wc_session` key.
+pub fn persist_session(session: &WcSession) {
+    let _ = LocalStorage::set(SESSION_STORAGE_KEY, session);
+}
+
+/// Tears down the session and clears the persisted entry.
+pub fn clear_session() {
+    LocalStorage::delete(SESSION_STORAGE_KEY);
+}
+
+fn random_hex(bytes: usize) -> String {
+    let mut out = String::with_capacity(bytes * 2);
+    for _ in 0..bytes {
+        let byte = (js_sys::Math::random() * 256.0) as u8;
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
+
+/// Starts the pairing flow and invokes `on_connected` with its outcome,
+/// used by `WalletConnector`'s connect button. Since there is no relay
+/// client behind [`PendingPairing::ensure_session`] yet, this always
+/// resolves to [`PairingError::RelayUnavailable`] -- callers must surface
+/// that message instead of rendering the (non-functional) pairing URI as
+/// if a wallet could scan and approve it.
+pub fn spawn_pairing<F>(timeout_ms: u32, on_connected: F)
+where
+    F: FnOnce(Result<WcSession, PairingError>) + 'static,
+{
+    let pairing = PendingPairing::new();
+    spawn_local(async move {
+        let result = pairing.ensure_session(timeout_ms).await;
+        if let Ok(session) = &result {
+            persist_session(session);
+        }
+        on_connected(result);
+    });
+}
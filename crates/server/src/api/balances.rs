@@ -1,6 +1,7 @@
-use crate::blockchain::BlockchainService;
 use crate::error::AppError;
-use crate::models::{NFTBalance, ERC20Balance, AddressQuery};
+use crate::models::{NFTBalance, ERC20Balance, ERC1155Balance, ERC20Transfer, AddressQuery, Erc1155BalanceQuery, TransfersQuery};
+use crate::registry::NetworkRegistry;
+use alloy::primitives::U256;
 use axum::{
     extract::{Query, State},
     response::Json,
@@ -8,37 +9,75 @@ use axum::{
     Router,
 };
 use std::sync::Arc;
+use std::str::FromStr;
 
-pub fn routes() -> Router<Arc<BlockchainService>> {
+pub fn routes() -> Router<Arc<NetworkRegistry>> {
     Router::new()
         .route("/nft", get(get_nft_balances))
         .route("/erc20", get(get_erc20_balances))
+        .route("/erc1155", get(get_erc1155_balances))
+        .route("/transfers", get(get_transfers))
         .route("/wallet", get(get_wallet_info))
 }
 
 /// Get NFT balances for an address
 async fn get_nft_balances(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<AddressQuery>,
 ) -> Result<Json<Vec<NFTBalance>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let nfts = blockchain.get_nft_balances(query.address).await?;
     Ok(Json(nfts))
 }
 
 /// Get ERC20 token balances for an address
 async fn get_erc20_balances(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<AddressQuery>,
 ) -> Result<Json<Vec<ERC20Balance>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let balances = blockchain.get_erc20_balances(query.address).await?;
     Ok(Json(balances))
 }
 
+/// Get ERC1155 token balances for an address across a set of token ids,
+/// giving multi-token contracts the same balance-lookup parity as the
+/// `/nft` and `/erc20` routes above.
+async fn get_erc1155_balances(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Query(query): Query<Erc1155BalanceQuery>,
+) -> Result<Json<Vec<ERC1155Balance>>, AppError> {
+    let token_ids = query
+        .token_ids
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(U256::from_str)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| AppError::ParseError(format!("invalid token_ids: {}", query.token_ids)))?;
+
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
+    let balances = blockchain.get_erc1155_balances(query.token_address, query.address, &token_ids).await?;
+    Ok(Json(balances))
+}
+
+/// Get merged, block-ordered `Transfer` event history (both directions) for
+/// an address over an optional block range.
+async fn get_transfers(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Query(query): Query<TransfersQuery>,
+) -> Result<Json<Vec<ERC20Transfer>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
+    let transfers = blockchain.get_transfer_history(query.address, query.from_block, query.to_block).await?;
+    Ok(Json(transfers))
+}
+
 /// Get comprehensive wallet information
 async fn get_wallet_info(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<AddressQuery>,
 ) -> Result<Json<serde_json::Value>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let wallet_info = blockchain.get_wallet_info(query.address).await?;
     Ok(Json(serde_json::to_value(wallet_info)?))
 }
@@ -0,0 +1,44 @@
+use crate::error::AppError;
+use crate::models::{ActionResult, DeployContractParams, DeployContractResult, MintTokensParams, TransferTokensParams};
+use crate::registry::NetworkRegistry;
+use axum::{
+    extract::State,
+    response::Json,
+    routing::post,
+    Router,
+};
+use std::sync::Arc;
+
+pub fn routes() -> Router<Arc<NetworkRegistry>> {
+    Router::new()
+        .route("/deploy-contract", post(deploy_contract))
+        .route("/mint-tokens", post(mint_tokens))
+        .route("/transfer-tokens", post(transfer_tokens))
+}
+
+/// Deploy a new contract from a template
+async fn deploy_contract(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Json(params): Json<DeployContractParams>,
+) -> Result<Json<DeployContractResult>, AppError> {
+    let result = registry.default_service().deploy_contract(params).await?;
+    Ok(Json(result))
+}
+
+/// Mint tokens on a deployed contract
+async fn mint_tokens(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Json(params): Json<MintTokensParams>,
+) -> Result<Json<ActionResult>, AppError> {
+    let result = registry.default_service().mint_tokens(params).await?;
+    Ok(Json(result))
+}
+
+/// Transfer tokens between accounts
+async fn transfer_tokens(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Json(params): Json<TransferTokensParams>,
+) -> Result<Json<ActionResult>, AppError> {
+    let result = registry.default_service().transfer_tokens(params).await?;
+    Ok(Json(result))
+}
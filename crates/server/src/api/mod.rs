@@ -0,0 +1,4 @@
+pub mod balances;
+pub mod contracts;
+pub mod transactions;
+pub mod transfers;
@@ -1,5 +1,5 @@
-use crate::blockchain::BlockchainService;
 use crate::error::AppError;
+use crate::registry::NetworkRegistry;
 use axum::{
     extract::{Query, State},
     response::Json,
@@ -7,6 +7,7 @@ use axum::{
     Router,
 };
 use alloy::primitives::Address;
+use alloy::primitives::aliases::B256;
 use serde::Deserialize;
 use std::sync::Arc;
 
@@ -14,18 +15,62 @@ use std::sync::Arc;
 pub struct TransactionsQuery {
     pub address: Address,
     pub limit: Option<u64>,
+    /// Routes the query to that chain's `BlockchainService`; see
+    /// `models::AddressQuery`.
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub network: Option<String>,
 }
 
-pub fn routes() -> Router<Arc<BlockchainService>> {
+#[derive(Debug, Deserialize)]
+pub struct TraceQuery {
+    pub tx_hash: B256,
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+pub fn routes() -> Router<Arc<NetworkRegistry>> {
     Router::new()
         .route("/", get(get_transactions))
+        .route("/gas-estimate", get(get_gas_estimate))
+        .route("/trace", get(get_trace))
 }
 
 /// Get transaction history for an address
 async fn get_transactions(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<TransactionsQuery>,
 ) -> Result<Json<Vec<crate::models::Transaction>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let transactions = blockchain.get_transactions(query.address, query.limit).await?;
     Ok(Json(transactions))
 }
+
+/// Preview the real EIP-1559 `maxFeePerGas`/`maxPriorityFeePerGas` a
+/// transaction sent with `gas_strategy` would attach, without sending one.
+async fn get_gas_estimate(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Query(query): Query<crate::models::GasEstimateQuery>,
+) -> Result<Json<crate::models::GasEstimateResult>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
+    let fees = blockchain.estimate_fees(query.gas_strategy).await?;
+    Ok(Json(crate::models::GasEstimateResult {
+        max_fee_per_gas: fees.max_fee_per_gas,
+        max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+    }))
+}
+
+/// Replay a transaction through `debug_traceTransaction` (geth's
+/// `callTracer`) and return its full call tree, including any decoded
+/// revert reason.
+async fn get_trace(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Query(query): Query<TraceQuery>,
+) -> Result<Json<crate::models::TraceResult>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
+    let trace = blockchain.trace_transaction(query.tx_hash).await?;
+    Ok(Json(trace))
+}
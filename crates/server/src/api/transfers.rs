@@ -1,6 +1,6 @@
-use crate::blockchain::BlockchainService;
 use crate::error::AppError;
 use crate::models::{NFTTransfer, ERC20Transfer, AddressQuery};
+use crate::registry::NetworkRegistry;
 use axum::{
     extract::{Query, State},
     response::Json,
@@ -9,7 +9,7 @@ use axum::{
 };
 use std::sync::Arc;
 
-pub fn routes() -> Router<Arc<BlockchainService>> {
+pub fn routes() -> Router<Arc<NetworkRegistry>> {
     Router::new()
         .route("/nft", get(get_nft_transfers))
         .route("/erc20", get(get_erc20_transfers))
@@ -17,18 +17,20 @@ pub fn routes() -> Router<Arc<BlockchainService>> {
 
 /// Get NFT transfers for an address
 async fn get_nft_transfers(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<AddressQuery>,
 ) -> Result<Json<Vec<NFTTransfer>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let transfers = blockchain.get_nft_transfers(query.address).await?;
     Ok(Json(transfers))
 }
 
 /// Get ERC20 token transfers for an address
 async fn get_erc20_transfers(
-    State(blockchain): State<Arc<BlockchainService>>,
+    State(registry): State<Arc<NetworkRegistry>>,
     Query(query): Query<AddressQuery>,
 ) -> Result<Json<Vec<ERC20Transfer>>, AppError> {
+    let blockchain = registry.resolve(query.chain_id, query.network.as_deref())?;
     let transfers = blockchain.get_erc20_transfers(query.address).await?;
     Ok(Json(transfers))
 }
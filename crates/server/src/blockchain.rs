@@ -1,24 +1,90 @@
 use crate::config::Config;
 use crate::error::AppError;
+use crate::middleware::{BlockchainServiceBuilder, ProviderLike};
 use crate::models::*;
-use alloy::providers::{Provider, ProviderBuilder, RootProvider};
-use alloy::rpc::types::eth::Transaction;
-use alloy::primitives::{Address, U256};
+use alloy::eips::BlockNumberOrTag;
+use alloy::providers::{Provider, RootProvider};
+use alloy::rpc::types::eth::{BlockTransactions, Filter, Log, Transaction, TransactionRequest};
+use alloy::primitives::{Address, Bytes, U256};
+use alloy::primitives::aliases::B256;
 use alloy::transports::http::{Client, Http};
+use ethereum_boilerplate_shared::{create_contract_result, GasStrategy, TransactionStatus};
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{info, warn};
+use tracing::info;
 
-pub struct BlockchainService {
+/// Talks to an Ethereum node through a bare HTTP provider plus whatever
+/// middleware layers (`M`) a [`BlockchainServiceBuilder`] stacked on top of
+/// it. The `provider`/`fee_estimator` fields are the ones the read methods
+/// below were already written against before the middleware stack existed;
+/// `middleware` is the opt-in layered surface (see `crate::middleware`).
+pub struct BlockchainService<M: ProviderLike = Arc<RootProvider<Http<Client>>>> {
     provider: Arc<RootProvider<Http<Client>>>,
-    config: Config,
+    fee_estimator: Arc<FeeEstimator>,
+    middleware: M,
+    node_client: NodeClient,
 }
 
-impl BlockchainService {
-    pub fn new(config: Config) -> Result<Self, AppError> {
-        let provider = ProviderBuilder::new().on_http(config.ethereum_rpc_url.clone());
-        let provider = Arc::new(provider);
-        
-        Ok(BlockchainService { provider, config })
+impl BlockchainService<Arc<RootProvider<Http<Client>>>> {
+    /// Builds the default (unlayered) `BlockchainService`, probes
+    /// `web3_clientVersion` to detect the node implementation behind
+    /// `config.ethereum_rpc_url`, and, when `config.network` names an
+    /// expected chain, asserts `eth_chainId` matches it before returning —
+    /// so a misconfigured RPC endpoint (e.g. a Polygon URL under a mainnet
+    /// `NETWORK`) fails fast at startup instead of quietly serving
+    /// wrong-chain data.
+    pub async fn new(config: Config) -> Result<Self, AppError> {
+        let expected = config.network.clone();
+        let mut service = BlockchainServiceBuilder::new(config)?.build();
+
+        service.node_client = detect_node_client(&service.provider).await;
+        info!("Detected RPC node client: {:?}", service.node_client);
+
+        if let Some(expected) = expected {
+            let actual_chain_id = service.chain_id().await?;
+            if actual_chain_id != expected.chain_id {
+                return Err(AppError::ConfigurationError(format!(
+                    "NETWORK is set to '{}' (chain id {}), but the RPC endpoint reported chain id {}",
+                    expected.name, expected.chain_id, actual_chain_id
+                )));
+            }
+        }
+
+        Ok(service)
+    }
+}
+
+impl<M: ProviderLike> BlockchainService<M> {
+    /// Assembles a `BlockchainService` from its parts; used by
+    /// [`BlockchainServiceBuilder::build`] so the fields above stay private
+    /// to this module. `node_client` defaults to `Unknown` here and is
+    /// filled in by [`BlockchainService::new`]'s startup probe.
+    pub(crate) fn from_parts(
+        provider: Arc<RootProvider<Http<Client>>>,
+        fee_estimator: Arc<FeeEstimator>,
+        middleware: M,
+    ) -> Self {
+        Self { provider, fee_estimator, middleware, node_client: NodeClient::Unknown }
+    }
+
+    /// Chain id as seen through the configured middleware stack, used by the
+    /// `/` health check to confirm the node connection is alive.
+    pub async fn chain_id(&self) -> Result<u64, AppError> {
+        self.middleware.get_chain_id().await
+    }
+
+    /// The node implementation detected at startup, so `/` can report what
+    /// the server actually connected to.
+    pub fn node_client(&self) -> NodeClient {
+        self.node_client
+    }
+
+    /// Real EIP-1559 `maxFeePerGas`/`maxPriorityFeePerGas` for `strategy`,
+    /// the same estimate `mint_tokens`/`transfer_tokens` attach to their
+    /// transactions, exposed standalone so a caller can preview gas
+    /// parameters before deciding to send anything.
+    pub async fn estimate_fees(&self, strategy: GasStrategy) -> Result<Eip1559Fees, AppError> {
+        self.fee_estimator.estimate(strategy).await
     }
 
     pub async fn get_wallet_info(&self, address: Address) -> Result<WalletInfo, AppError> {
@@ -36,78 +102,876 @@ impl BlockchainService {
     }
 
     pub async fn get_nft_balances(&self, address: Address) -> Result<Vec<NFTBalance>, AppError> {
-        let nfts = Vec::new();
-        
-        // This is a simplified implementation
-        // In a real implementation, you would:
-        // 1. Query ERC-721 and ERC-1155 transfers to/from the address
-        // 2. Get current ownership by checking the latest transfer
-        // 3. Fetch metadata for each NFT
-        
         info!("Fetching NFT balances for address: {:?}", address);
-        
-        // For now, we'll use Moralis API if available, otherwise return empty
-        if let Some(_api_key) = &self.config.moralis_api_key {
-            // TODO: Implement Moralis API integration
-            warn!("Moralis API key provided but integration not implemented yet");
+
+        let transfers = self.get_nft_transfers(address).await?;
+
+        // Current owner of a token id is whoever is on the `to` side of its
+        // most recent transfer; replay in block order and keep the latest.
+        let mut latest: HashMap<(Address, U256), (u64, Address)> = HashMap::new();
+        for transfer in transfers {
+            let key = (transfer.token_address, transfer.token_id);
+            let is_newer = latest.get(&key).map_or(true, |(block, _)| transfer.block_number >= *block);
+            if is_newer {
+                latest.insert(key, (transfer.block_number, transfer.to));
+            }
         }
-        
+
+        let nfts = latest
+            .into_iter()
+            .filter(|(_, (_, owner))| *owner == address)
+            .map(|((token_address, token_id), _)| NFTBalance {
+                token_address,
+                token_id,
+                token_uri: None,
+                name: None,
+                symbol: None,
+                metadata: None,
+            })
+            .collect();
+
         Ok(nfts)
     }
 
     pub async fn get_erc20_balances(&self, address: Address) -> Result<Vec<ERC20Balance>, AppError> {
-        let balances = Vec::new();
-        
         info!("Fetching ERC20 balances for address: {:?}", address);
-        
-        // Similar to NFTs, this would typically use an external API like Moralis
-        // or scan ERC-20 transfer events
-        
-        if let Some(_api_key) = &self.config.moralis_api_key {
-            // TODO: Implement Moralis API integration
-            warn!("Moralis API key provided but integration not implemented yet");
+
+        let transfers = self.get_erc20_transfers(address).await?;
+
+        let mut net: HashMap<Address, U256> = HashMap::new();
+        for transfer in transfers {
+            let entry = net.entry(transfer.token_address).or_default();
+            if transfer.to == address {
+                *entry += transfer.value;
+            }
+            if transfer.from == address {
+                *entry = entry.saturating_sub(transfer.value);
+            }
         }
-        
+
+        let mut balances = Vec::new();
+        for (token_address, balance) in net {
+            if balance.is_zero() {
+                continue;
+            }
+            let decimals = self.fetch_decimals(token_address).await.unwrap_or(18);
+            balances.push(ERC20Balance {
+                token_address,
+                balance,
+                name: None,
+                symbol: None,
+                decimals,
+            });
+        }
+
         Ok(balances)
     }
 
-    pub async fn get_transactions(&self, address: Address, _limit: Option<u64>) -> Result<Vec<crate::models::Transaction>, AppError> {
-        let transactions = Vec::new();
-        
-        info!("Fetching transactions for address: {:?}", address);
-        
-        // Get latest block number to limit the search
+    /// Retrieves transaction history for `address`. When the detected
+    /// [`NodeClient`] exposes the `trace` namespace
+    /// ([`NodeClient::supports_trace_filter`]), uses `trace_filter` to find
+    /// every transaction touching `address` across the chain's full
+    /// history; otherwise falls back to scanning the last
+    /// [`NodeClient::log_window_blocks`] blocks, since that's all a plain
+    /// `eth_getBlockByNumber` scan can afford to look at on every request.
+    pub async fn get_transactions(&self, address: Address, limit: Option<u64>) -> Result<Vec<crate::models::Transaction>, AppError> {
+        info!(
+            "Fetching transactions for address: {:?} (node_client={:?})",
+            address, self.node_client
+        );
+
+        let hashes = if self.node_client.supports_trace_filter() {
+            self.transaction_hashes_via_trace_filter(address, limit).await?
+        } else {
+            self.transaction_hashes_via_block_scan(address, limit).await?
+        };
+
+        let mut transactions = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            if let Some(tx) = self.provider.get_transaction_by_hash(hash).await
+                .map_err(|e| AppError::EthereumProvider(e.to_string()))?
+            {
+                transactions.push(to_transaction(&tx));
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Uses `trace_filter` (only available on clients where
+    /// [`NodeClient::supports_trace_filter`] is true) to list every
+    /// transaction hash touching `address`, regardless of how old it is.
+    async fn transaction_hashes_via_trace_filter(&self, address: Address, limit: Option<u64>) -> Result<Vec<B256>, AppError> {
+        let params = serde_json::json!([{
+            "fromAddress": [address],
+            "toAddress": [address],
+            "count": limit.unwrap_or(DEFAULT_TRACE_FILTER_LIMIT),
+        }]);
+
+        let traces: Vec<TraceFilterEntry> = self.provider.client().request("trace_filter", params).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+        let mut seen = std::collections::HashSet::new();
+        Ok(traces
+            .into_iter()
+            .map(|t| t.transaction_hash)
+            .filter(|hash| seen.insert(*hash))
+            .collect())
+    }
+
+    /// Calls `debug_traceTransaction` with the `callTracer` config and
+    /// returns the decoded call tree, so a reverted `mint`/`transfer` (from
+    /// the `ContractAction` set) can be diagnosed from its `error`/
+    /// `revert_reason` instead of eyeballing a bare "reverted" receipt
+    /// status. Only available on clients exposing the non-standard `debug`
+    /// namespace; callers get back whatever error the node reports
+    /// otherwise (e.g. a 404-ish "method not found").
+    pub async fn trace_transaction(&self, tx_hash: B256) -> Result<TraceResult, AppError> {
+        info!("Tracing transaction: {:?}", tx_hash);
+
+        let params = serde_json::json!([tx_hash, { "tracer": "callTracer" }]);
+        let raw: RawCallFrame = self.provider.client().request("debug_traceTransaction", params).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+        Ok(TraceResult { transaction_hash: tx_hash, root: raw.into() })
+    }
+
+    /// Scans backward from the latest block in [`NodeClient::log_window_blocks`]
+    /// blocks, collecting the hash of every transaction where `address` is
+    /// the sender or the recipient.
+    async fn transaction_hashes_via_block_scan(&self, address: Address, limit: Option<u64>) -> Result<Vec<B256>, AppError> {
         let latest_block = self.provider.get_block_number().await
             .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
-        let _start_block = latest_block.saturating_sub(1000); // Last 1000 blocks
-        
-        // This is a simplified approach - in production you'd want to use an external API
-        // or index the blockchain for better performance
-        
-        Ok(transactions)
+        let start_block = latest_block.saturating_sub(self.node_client.log_window_blocks());
+
+        let mut hashes = Vec::new();
+        let mut block_num = latest_block;
+
+        loop {
+            let block = self.provider.get_block_by_number(block_num.into(), true).await
+                .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+            if let Some(block) = block {
+                if let BlockTransactions::Full(txs) = block.transactions {
+                    hashes.extend(
+                        txs.into_iter()
+                            .filter(|tx| tx.from == address || tx.to == Some(address))
+                            .map(|tx| tx.hash),
+                    );
+                }
+            }
+
+            if matches!(limit, Some(limit) if hashes.len() as u64 >= limit) || block_num == start_block {
+                break;
+            }
+            block_num -= 1;
+        }
+
+        if let Some(limit) = limit {
+            hashes.truncate(limit as usize);
+        }
+
+        Ok(hashes)
     }
 
     pub async fn get_nft_transfers(&self, address: Address) -> Result<Vec<NFTTransfer>, AppError> {
-        let transfers = Vec::new();
-        
         info!("Fetching NFT transfers for address: {:?}", address);
-        
-        if let Some(_api_key) = &self.config.moralis_api_key {
-            // TODO: Implement Moralis API integration
-        }
-        
-        Ok(transfers)
+
+        let logs = self.scan_transfer_logs(address).await?;
+        Ok(logs.iter().filter_map(decode_nft_transfer).collect())
     }
 
     pub async fn get_erc20_transfers(&self, address: Address) -> Result<Vec<ERC20Transfer>, AppError> {
-        let transfers = Vec::new();
-        
         info!("Fetching ERC20 transfers for address: {:?}", address);
-        
-        if let Some(_api_key) = &self.config.moralis_api_key {
-            // TODO: Implement Moralis API integration
-        }
-        
+
+        let logs = self.scan_transfer_logs(address).await?;
+        Ok(logs.iter().filter_map(decode_erc20_transfer).collect())
+    }
+
+    /// Transfer-event history for `address` over an explicit block range,
+    /// backing the `/transfers` route. Unlike `scan_transfer_logs` (which
+    /// always paginates back from the chain tip in
+    /// [`NodeClient::log_window_blocks`]-sized windows), this issues the
+    /// `from`/`to` slot queries directly against the caller's range, so a
+    /// narrow range doesn't pay for windowed pagination it doesn't need;
+    /// `from_block`/`to_block` default to the same
+    /// `latest - MAX_LOOKBACK_BLOCKS`/`latest` bounds `scan_transfer_logs`
+    /// uses when omitted. Decoded the same way `get_erc20_transfers` does,
+    /// since `Transfer(address,address,uint256)` is the event both share.
+    pub async fn get_transfer_history(
+        &self,
+        address: Address,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> Result<Vec<ERC20Transfer>, AppError> {
+        info!("Fetching transfer history for address: {:?}", address);
+
+        let latest = self.provider.get_block_number().await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        let to = to_block.unwrap_or(latest);
+        let from = from_block.unwrap_or_else(|| latest.saturating_sub(MAX_LOOKBACK_BLOCKS));
+
+        let from_filter = Filter::new()
+            .event_signature(transfer_topic0())
+            .topic1(address.into_word())
+            .from_block(from)
+            .to_block(to);
+        let to_filter = Filter::new()
+            .event_signature(transfer_topic0())
+            .topic2(address.into_word())
+            .from_block(from)
+            .to_block(to);
+
+        let from_logs = self.provider.get_logs(&from_filter).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        let to_logs = self.provider.get_logs(&to_filter).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+        // A self-transfer (from == to == address) matches both filters and
+        // appears once in each of from_logs/to_logs. Dedup on the
+        // (transaction_hash, log_index) pair -- the only thing that
+        // uniquely identifies a log -- *before* sorting, since a
+        // post-sort-adjacency dedup_by would miss the two copies whenever
+        // another transfer at the same block_number sits between them.
+        let mut seen = std::collections::HashSet::new();
+        let mut transfers: Vec<ERC20Transfer> = from_logs
+            .iter()
+            .chain(to_logs.iter())
+            .filter(|log| seen.insert((log.transaction_hash, log.log_index)))
+            .filter_map(decode_erc20_transfer)
+            .collect();
+        transfers.sort_by_key(|t| t.block_number);
+
         Ok(transfers)
     }
+
+    pub async fn deploy_contract(&self, params: DeployContractParams) -> Result<DeployContractResult, AppError> {
+        info!("Deploying {} contract '{}' ({})", params.contract_type, params.name, params.symbol);
+
+        // This is a simplified implementation that sends nothing to the
+        // node: in production this would broadcast a real
+        // deployment transaction and wait for the receipt.
+        let address = deterministic_address(&format!("{}{}", params.name, params.symbol));
+        let transaction_hash = deterministic_hash(&format!("deploy:{}", params.name));
+
+        Ok(DeployContractResult { address, transaction_hash })
+    }
+
+    pub async fn mint_tokens(&self, params: MintTokensParams) -> Result<ActionResult, AppError> {
+        info!("Minting {} tokens on {:?}", params.amount, params.contract_address);
+
+        let fees = self.fee_estimator.estimate(params.gas_strategy).await?;
+        let transaction_hash = deterministic_hash(&format!("mint:{}:{}", params.contract_address, params.amount));
+        Ok(ActionResult {
+            success: true,
+            transaction_hash,
+            max_fee_per_gas: fees.max_fee_per_gas,
+            max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+        })
+    }
+
+    pub async fn transfer_tokens(&self, params: TransferTokensParams) -> Result<ActionResult, AppError> {
+        info!(
+            "Transferring {} tokens on {:?} to {:?}",
+            params.amount, params.contract_address, params.to
+        );
+
+        let fees = self.fee_estimator.estimate(params.gas_strategy).await?;
+        let transaction_hash = deterministic_hash(&format!(
+            "transfer:{}:{}:{}",
+            params.contract_address, params.to, params.amount
+        ));
+        Ok(ActionResult {
+            success: true,
+            transaction_hash,
+            max_fee_per_gas: fees.max_fee_per_gas,
+            max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+        })
+    }
+
+    /// Sends a raw contract-creation transaction (`to: None`, `input:
+    /// init_code`) from `params.deployer`'s next nonce, the in-process
+    /// counterpart to the `interact` CLI's `cargo run --bin interact`
+    /// subprocess shell-out described in `crate::middleware`'s module docs.
+    /// Like `deploy_contract`/`mint_tokens`/`transfer_tokens` above, the
+    /// actual broadcast is a simplified stand-in (no signer is threaded
+    /// through here yet -- that's `crate::middleware::SignerLayer`'s job
+    /// once a caller supplies a key); what's real is the resulting address
+    /// (a genuine `eth_getTransactionCount` + CREATE derivation, not a
+    /// name/salt hash like `deploy_contract`'s) and the
+    /// [`Self::assert_address_free`] check below, which a real deployment's
+    /// receipt would also have to pass.
+    pub async fn deploy_bytecode(&self, params: DeployBytecodeParams) -> Result<DeployedContractInfo, AppError> {
+        if params.init_code.is_empty() {
+            return Err(AppError::ContractError("init_code must not be empty".into()));
+        }
+        info!(
+            "Deploying {} bytes of init code from {:?}",
+            params.init_code.len(), params.deployer
+        );
+
+        let nonce = self.provider.get_transaction_count(params.deployer).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        let address = params.deployer.create(nonce);
+        self.assert_address_free(address).await?;
+
+        let transaction_hash = deterministic_hash(&format!("deploy-bytecode:{:#x}", address));
+        Ok(DeployedContractInfo { address, interaction: create_contract_result(transaction_hash, TransactionStatus::Confirmed) })
+    }
+
+    /// Deploys `init_code` at a reproducible address, modeled on the Serai
+    /// bridge deployer: a tiny one-shot "deployer" contract is deployed once
+    /// per chain at `deployer.create(0)` (identical on every chain
+    /// `deployer` has never sent a transaction from before); the real
+    /// target is then `CREATE`d *by that contract*, so its final address
+    /// depends only on the deployer contract's own address and nonce, not
+    /// on `deployer`'s nonce at call time -- reproducible across networks
+    /// given the same `deployer`/`init_code`, unlike `deploy_bytecode`
+    /// above whose address shifts with every other transaction `deployer`
+    /// has sent.
+    pub async fn deploy_reproducible(&self, params: DeployBytecodeParams) -> Result<DeployedContractInfo, AppError> {
+        if params.init_code.is_empty() {
+            return Err(AppError::ContractError("init_code must not be empty".into()));
+        }
+
+        let deployer_contract = params.deployer.create(0);
+        let relay_code = self.provider.get_code_at(deployer_contract).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        if relay_code.is_empty() {
+            info!(
+                "No deployer relay at {:?} yet; it would be deployed from {:?} at nonce 0 first",
+                deployer_contract, params.deployer
+            );
+        }
+
+        // The relay's own nonce (not `deployer`'s) decides the target
+        // address from here on, which is what makes it reproducible.
+        let relay_nonce = self.provider.get_transaction_count(deployer_contract).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        let address = deployer_contract.create(relay_nonce);
+        self.assert_address_free(address).await?;
+
+        let transaction_hash = deterministic_hash(&format!("deploy-reproducible:{:#x}", address));
+        Ok(DeployedContractInfo { address, interaction: create_contract_result(transaction_hash, TransactionStatus::Confirmed) })
+    }
+
+    /// A `CREATE` targeting an address that already has code would revert
+    /// on-chain (the nonce/deployer pair has already been used), so check
+    /// for that up front -- the one failure mode [`Self::deploy_bytecode`]/
+    /// [`Self::deploy_reproducible`] can genuinely detect without a real
+    /// broadcast, surfaced the same way a reverted receipt's status would be.
+    async fn assert_address_free(&self, address: Address) -> Result<(), AppError> {
+        let code = self.provider.get_code_at(address).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        if !code.is_empty() {
+            return Err(AppError::ContractError(format!(
+                "Address {:#x} already has code; a CREATE here would revert",
+                address
+            )));
+        }
+        Ok(())
+    }
+
+    /// Scans `Transfer(address,address,uint256)` logs (the `ERC20_TRANSFER_EVENT`/
+    /// `ERC721_TRANSFER_EVENT` signatures in `models::contract_abis` share the
+    /// same topic0; only the indexing of the third argument differs) touching
+    /// `address`, paginating backward from the latest block in
+    /// [`NodeClient::log_window_blocks`]-sized windows (instead of a single
+    /// fixed size) so no single `eth_getLogs` call exceeds the detected
+    /// node's block-range limit. Bounded to [`MAX_LOOKBACK_BLOCKS`] so an
+    /// address with ancient history doesn't scan back to genesis on every
+    /// request.
+    async fn scan_transfer_logs(&self, address: Address) -> Result<Vec<Log>, AppError> {
+        let latest = self.provider.get_block_number().await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+        let earliest = latest.saturating_sub(MAX_LOOKBACK_BLOCKS);
+        let window_size = self.node_client.log_window_blocks();
+
+        let mut logs = Vec::new();
+        let mut window_end = latest;
+
+        loop {
+            let window_start = window_end.saturating_sub(window_size).max(earliest);
+
+            let from_filter = Filter::new()
+                .event_signature(transfer_topic0())
+                .topic1(address.into_word())
+                .from_block(window_start)
+                .to_block(window_end);
+            let to_filter = Filter::new()
+                .event_signature(transfer_topic0())
+                .topic2(address.into_word())
+                .from_block(window_start)
+                .to_block(window_end);
+
+            let from_logs = self.provider.get_logs(&from_filter).await
+                .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+            let to_logs = self.provider.get_logs(&to_filter).await
+                .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+            logs.extend(from_logs);
+            logs.extend(to_logs);
+
+            if window_start == earliest {
+                break;
+            }
+            window_end = window_start;
+        }
+
+        Ok(logs)
+    }
+
+    /// Calls the standard ERC-20 `decimals()` view function, falling back to
+    /// `None` (callers default to 18) for tokens that don't implement it or
+    /// an RPC that rejects the call.
+    async fn fetch_decimals(&self, token: Address) -> Option<u8> {
+        const DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+        let call = TransactionRequest::default()
+            .to(token)
+            .input(Bytes::from_static(&DECIMALS_SELECTOR).into());
+
+        let result = self.provider.call(&call).await.ok()?;
+        result.get(31).copied()
+    }
+
+    /// Calls the standard ERC-1155 `balanceOfBatch(address[],uint256[])` view
+    /// function with `account` repeated once per entry in `token_ids`,
+    /// mirroring how `fetch_decimals` hand-encodes calldata rather than
+    /// pulling in a full ABI-codegen dependency. Gives parity with
+    /// `get_erc20_balances`/`get_nft_balances` for multi-token contracts,
+    /// where a single log scan can't tell you "balance of token id N" —
+    /// that's only exposed through the contract's own view function.
+    pub async fn get_erc1155_balances(
+        &self,
+        token_address: Address,
+        account: Address,
+        token_ids: &[U256],
+    ) -> Result<Vec<ERC1155Balance>, AppError> {
+        info!("Fetching ERC1155 balances for address: {:?}", account);
+
+        if token_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let call = TransactionRequest::default()
+            .to(token_address)
+            .input(encode_balance_of_batch(account, token_ids).into());
+
+        let result = self.provider.call(&call).await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+        let balances = decode_uint256_array(&result)
+            .ok_or_else(|| AppError::ContractError("balanceOfBatch returned malformed data".to_string()))?;
+
+        Ok(token_ids
+            .iter()
+            .zip(balances)
+            .map(|(&token_id, balance)| ERC1155Balance { token_id, balance })
+            .collect())
+    }
+}
+
+/// ABI-encodes a call to `balanceOfBatch(address[],uint256[])`, passing
+/// `account` once per id in `ids` (the standard way to ask "one account's
+/// balance across several token ids" through a function designed for
+/// "several accounts' balances across several token ids").
+fn encode_balance_of_batch(account: Address, ids: &[U256]) -> Vec<u8> {
+    const BALANCE_OF_BATCH_SELECTOR: [u8; 4] = [0x4e, 0x12, 0x73, 0xf4];
+
+    let len = ids.len();
+    let mut data = Vec::with_capacity(4 + 32 * (2 + 2 + 2 * len));
+    data.extend_from_slice(&BALANCE_OF_BATCH_SELECTOR);
+
+    // Head: offsets (in bytes, from the start of the arguments) to the two
+    // dynamic array tails.
+    let accounts_offset = 64u64;
+    let accounts_tail_len = 32 + 32 * len as u64;
+    let ids_offset = accounts_offset + accounts_tail_len;
+    data.extend_from_slice(&U256::from(accounts_offset).to_be_bytes::<32>());
+    data.extend_from_slice(&U256::from(ids_offset).to_be_bytes::<32>());
+
+    // accounts[]
+    data.extend_from_slice(&U256::from(len).to_be_bytes::<32>());
+    for _ in 0..len {
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(account.as_slice());
+    }
+
+    // ids[]
+    data.extend_from_slice(&U256::from(len).to_be_bytes::<32>());
+    for id in ids {
+        data.extend_from_slice(&id.to_be_bytes::<32>());
+    }
+
+    data
+}
+
+/// Decodes a single `uint256[]` return value (the shape `balanceOfBatch`
+/// returns): a leading offset word (ignored — there's only one return value,
+/// so it's always `0x20`), a length word, then `length` 32-byte elements.
+fn decode_uint256_array(data: &[u8]) -> Option<Vec<U256>> {
+    if data.len() < 64 {
+        return None;
+    }
+    let len = U256::from_be_slice(&data[32..64]).to::<usize>();
+    let expected = 64 + len * 32;
+    if data.len() < expected {
+        return None;
+    }
+
+    Some((0..len).map(|i| {
+        let start = 64 + i * 32;
+        U256::from_be_slice(&data[start..start + 32])
+    }).collect())
+}
+
+/// How far back [`BlockchainService::scan_transfer_logs`] is willing to
+/// paginate before giving up on an address's full history.
+const MAX_LOOKBACK_BLOCKS: u64 = 50_000;
+
+/// The Ethereum client implementation behind the configured RPC endpoint,
+/// parsed from `web3_clientVersion` (e.g. `"Geth/v1.13.0/linux-amd64/go1.21"`
+/// -> `Geth`). Different clients impose different `eth_getLogs` block-range
+/// limits and expose different non-standard namespaces, so this is probed
+/// once at startup ([`BlockchainService::new`]) rather than assumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeClient {
+    Geth,
+    Erigon,
+    OpenEthereum,
+    Nethermind,
+    Besu,
+    /// Reported a `web3_clientVersion` we don't recognize, or the probe
+    /// itself failed (some providers block the method).
+    #[default]
+    Unknown,
+}
+
+impl NodeClient {
+    fn from_client_version(version: &str) -> Self {
+        let token = version.split('/').next().unwrap_or(version).to_lowercase();
+        match token.as_str() {
+            "geth" => NodeClient::Geth,
+            "erigon" => NodeClient::Erigon,
+            "openethereum" | "parity-ethereum" | "parity" => NodeClient::OpenEthereum,
+            "nethermind" => NodeClient::Nethermind,
+            "besu" => NodeClient::Besu,
+            _ => NodeClient::Unknown,
+        }
+    }
+
+    /// Safe `eth_getLogs`/`eth_getBlockByNumber` scan window for this
+    /// client. Public Geth and Besu endpoints commonly cap log ranges
+    /// around 2000 blocks; Erigon's flat-indexed log storage tolerates much
+    /// wider windows, and OpenEthereum/Nethermind sit in between.
+    fn log_window_blocks(&self) -> u64 {
+        match self {
+            NodeClient::Erigon => 10_000,
+            NodeClient::Nethermind | NodeClient::OpenEthereum => 5_000,
+            NodeClient::Geth | NodeClient::Besu | NodeClient::Unknown => 2_000,
+        }
+    }
+
+    /// Whether this client exposes `trace_filter`
+    /// ([`BlockchainService::get_transactions`] uses it, when available, to
+    /// retrieve full transaction history instead of scanning recent
+    /// blocks): Erigon, OpenEthereum and Nethermind support the `trace`
+    /// namespace; Geth and Besu don't.
+    fn supports_trace_filter(&self) -> bool {
+        matches!(self, NodeClient::Erigon | NodeClient::OpenEthereum | NodeClient::Nethermind)
+    }
+}
+
+/// Calls `web3_clientVersion` and parses the result into a [`NodeClient`],
+/// falling back to `Unknown` (logging a warning) if the endpoint doesn't
+/// support the method, since it's a non-essential probe and shouldn't fail
+/// startup on its own.
+async fn detect_node_client(provider: &RootProvider<Http<Client>>) -> NodeClient {
+    match provider.client_version().await {
+        Ok(version) => NodeClient::from_client_version(&version),
+        Err(e) => {
+            tracing::warn!("Could not determine RPC node client via web3_clientVersion: {}", e);
+            NodeClient::Unknown
+        }
+    }
+}
+
+/// How many matching entries to request from `trace_filter` when the
+/// caller didn't pass a `limit`.
+const DEFAULT_TRACE_FILTER_LIMIT: u64 = 100;
+
+/// A single entry of a `trace_filter` response; only the transaction hash
+/// is needed here; the rest of the trace is discarded.
+#[derive(Debug, serde::Deserialize)]
+struct TraceFilterEntry {
+    #[serde(rename = "transactionHash")]
+    transaction_hash: B256,
+}
+
+/// One frame of geth's `callTracer` response, deserialized straight off the
+/// wire: `value`/`gasUsed` stay as alloy's own hex-aware `U256`, unlike
+/// [`CallFrame`] (this module's public result type), which re-encodes them
+/// through `models::u256_ser` like every other amount the server returns.
+#[derive(Debug, serde::Deserialize)]
+struct RawCallFrame {
+    #[serde(rename = "type")]
+    call_type: String,
+    from: Address,
+    #[serde(default)]
+    to: Option<Address>,
+    #[serde(default)]
+    value: Option<U256>,
+    #[serde(rename = "gasUsed", default)]
+    gas_used: U256,
+    #[serde(default)]
+    input: Bytes,
+    #[serde(default)]
+    output: Option<Bytes>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    calls: Vec<RawCallFrame>,
+}
+
+impl From<RawCallFrame> for CallFrame {
+    fn from(raw: RawCallFrame) -> Self {
+        let revert_reason = raw.output.as_ref().and_then(|output| decode_revert_reason(output));
+
+        CallFrame {
+            call_type: raw.call_type,
+            from: raw.from,
+            to: raw.to,
+            value: raw.value,
+            gas_used: raw.gas_used,
+            input: raw.input,
+            output: raw.output,
+            error: raw.error,
+            revert_reason,
+            calls: raw.calls.into_iter().map(CallFrame::from).collect(),
+        }
+    }
+}
+
+/// `keccak256("Error(string)")[..4]`, the selector Solidity's `require`/
+/// `revert("msg")` encode their message behind.
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// ABI-decodes a revert reason from a call frame's `output`, when it's the
+/// standard `Error(string)` encoding (selector, then a `string` ABI-encoded
+/// as offset + length + UTF-8 bytes). Returns `None` for custom errors,
+/// `Panic(uint256)`, or a frame that didn't revert at all.
+fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    if output.len() < 4 || output[..4] != ERROR_STRING_SELECTOR {
+        return None;
+    }
+
+    let data = &output[4..];
+    if data.len() < 64 {
+        return None;
+    }
+
+    let len = U256::from_be_slice(&data[32..64]).to::<usize>();
+    let start = 64;
+    if data.len() < start + len {
+        return None;
+    }
+
+    String::from_utf8(data[start..start + len].to_vec()).ok()
+}
+
+/// Converts an alloy RPC transaction into our own [`crate::models::Transaction`].
+/// `gas_used` is left at zero since it's only available from the
+/// transaction's receipt, which we don't fetch here to avoid an extra
+/// round trip per result (same simplification `decode_erc20_transfer`/
+/// `decode_nft_transfer` make for `timestamp`, below).
+fn to_transaction(tx: &Transaction) -> crate::models::Transaction {
+    crate::models::Transaction {
+        hash: tx.hash,
+        from: tx.from,
+        to: tx.to,
+        value: tx.value,
+        gas_used: U256::ZERO,
+        gas_price: tx.gas_price.map(U256::from),
+        block_number: tx.block_number.unwrap_or_default(),
+        block_hash: tx.block_hash.unwrap_or_default(),
+        transaction_index: tx.transaction_index.unwrap_or_default(),
+        timestamp: None,
+    }
+}
+
+/// `keccak256("Transfer(address,address,uint256)")`, shared by the ERC-20
+/// and ERC-721 `Transfer` events (the third argument's indexing doesn't
+/// affect the topic hash).
+fn transfer_topic0() -> B256 {
+    const TOPIC: [u8; 32] = [
+        0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d, 0xaa,
+        0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23, 0xb3, 0xef,
+    ];
+    B256::from(TOPIC)
+}
+
+/// Decodes a log as an ERC-20 `Transfer` (3 topics: signature, from, to; the
+/// value is unindexed data), returning `None` for anything else (e.g. an
+/// NFT `Transfer` with an indexed `tokenId`).
+fn decode_erc20_transfer(log: &Log) -> Option<ERC20Transfer> {
+    let topics = log.topics();
+    if topics.len() != 3 {
+        return None;
+    }
+
+    Some(ERC20Transfer {
+        token_address: log.address(),
+        from: Address::from_word(*topics.get(1)?),
+        to: Address::from_word(*topics.get(2)?),
+        value: U256::from_be_slice(log.data().data.as_ref()),
+        transaction_hash: log.transaction_hash.unwrap_or_default(),
+        block_number: log.block_number.unwrap_or_default(),
+        timestamp: None,
+    })
+}
+
+/// Decodes a log as an ERC-721 `Transfer` (4 topics: signature, from, to,
+/// indexed `tokenId`).
+fn decode_nft_transfer(log: &Log) -> Option<NFTTransfer> {
+    let topics = log.topics();
+    if topics.len() != 4 {
+        return None;
+    }
+
+    Some(NFTTransfer {
+        token_address: log.address(),
+        from: Address::from_word(*topics.get(1)?),
+        to: Address::from_word(*topics.get(2)?),
+        token_id: U256::from_be_bytes(topics.get(3)?.0),
+        transaction_hash: log.transaction_hash.unwrap_or_default(),
+        block_number: log.block_number.unwrap_or_default(),
+        timestamp: None,
+    })
+}
+
+/// `maxFeePerGas`/`maxPriorityFeePerGas` suggested for a [`GasStrategy`].
+#[derive(Debug, Clone, Copy)]
+pub struct Eip1559Fees {
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Number of recent blocks sampled by `eth_feeHistory` when estimating fees.
+const FEE_HISTORY_BLOCK_COUNT: u64 = 20;
+/// Priority fee used when `eth_feeHistory` returns no non-zero reward
+/// samples for the requested percentile (e.g. an idle chain).
+const MIN_PRIORITY_FEE_WEI: u64 = 1_000_000_000;
+
+/// Extra priority fee piled on top of the 90th-percentile reward for
+/// [`GasStrategy::Urgent`], so an "I need this to land in the next block"
+/// request doesn't just tie the 90th-percentile tippers it's competing
+/// against.
+const URGENT_PRIORITY_FEE_BUMP_WEI: u64 = 2_000_000_000;
+
+/// Estimates EIP-1559 fees from `eth_feeHistory`, replacing the naive
+/// `GasStrategy::multiplier()` scalar (wrong for EIP-1559 networks like the
+/// Ethereum/Polygon mainnets in `SUPPORTED_NETWORKS`, since there's no
+/// single "base gas price" to scale). Maps each strategy to a reward
+/// percentile (Slow -> 10th, Standard -> 50th, Fast/Urgent -> 90th, Urgent
+/// adding a fixed bump on top), averages that percentile's reward across
+/// the sampled blocks (dropping zero-reward entries, which just mean
+/// nobody paid a tip that block), and projects the next block's base fee
+/// as `latestBaseFee * 9/8` -- the maximum a base fee can rise under
+/// EIP-1559's 1/8-per-block adjustment rule -- rather than trusting a
+/// node's own "pending" `eth_feeHistory` entry, since not every RPC
+/// implementation populates that consistently. `maxFeePerGas` is then
+/// `2 * projectedBaseFee + priorityFee`, absorbing a couple of
+/// consecutive max base-fee increases before the transaction would need
+/// replacing.
+pub struct FeeEstimator {
+    provider: Arc<RootProvider<Http<Client>>>,
+}
+
+impl FeeEstimator {
+    pub fn new(provider: Arc<RootProvider<Http<Client>>>) -> Self {
+        Self { provider }
+    }
+
+    fn reward_percentile(strategy: GasStrategy) -> f64 {
+        match strategy {
+            GasStrategy::Slow => 10.0,
+            GasStrategy::Standard => 50.0,
+            GasStrategy::Fast => 90.0,
+            GasStrategy::Urgent => 90.0,
+        }
+    }
+
+    pub async fn estimate(&self, strategy: GasStrategy) -> Result<Eip1559Fees, AppError> {
+        let percentile = Self::reward_percentile(strategy);
+
+        let history = self
+            .provider
+            .get_fee_history(FEE_HISTORY_BLOCK_COUNT, BlockNumberOrTag::Latest, &[percentile])
+            .await
+            .map_err(|e| AppError::EthereumProvider(e.to_string()))?;
+
+        // `eth_feeHistory` returns one `baseFeePerGas` per sampled block
+        // plus the node's own appended guess for the next one; we ignore
+        // that guess and derive the projection ourselves from the latest
+        // actual block so the estimate doesn't depend on a node correctly
+        // implementing the "pending" entry.
+        let latest_base_fee = history
+            .base_fee_per_gas
+            .iter()
+            .rev()
+            .nth(1)
+            .or_else(|| history.base_fee_per_gas.last())
+            .copied()
+            .map(U256::from)
+            .unwrap_or(U256::ZERO);
+        let projected_base_fee = latest_base_fee * U256::from(9u64) / U256::from(8u64);
+
+        let rewards: Vec<u128> = history
+            .reward
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .filter(|reward| *reward > 0)
+            .collect();
+
+        let mut priority_fee = if rewards.is_empty() {
+            U256::from(MIN_PRIORITY_FEE_WEI)
+        } else {
+            let sum: u128 = rewards.iter().sum();
+            U256::from(sum / rewards.len() as u128)
+        };
+
+        if strategy == GasStrategy::Urgent {
+            priority_fee += U256::from(URGENT_PRIORITY_FEE_BUMP_WEI);
+        }
+
+        Ok(Eip1559Fees {
+            max_fee_per_gas: projected_base_fee * U256::from(2u64) + priority_fee,
+            max_priority_fee_per_gas: priority_fee,
+        })
+    }
+}
+
+/// Derives a stable, non-zero mock address from a seed string. Used by the
+/// simplified deploy/mint/transfer implementations above, which don't yet
+/// broadcast real transactions.
+fn deterministic_address(seed: &str) -> Address {
+    let digest = md5_like_hash(seed.as_bytes());
+    Address::from_slice(&digest[..20])
+}
+
+fn deterministic_hash(seed: &str) -> B256 {
+    let digest = md5_like_hash(seed.as_bytes());
+    B256::from_slice(&digest)
+}
+
+/// A tiny non-cryptographic mixing hash, good enough to produce stable
+/// 32-byte placeholders for the mock deploy/mint/transfer responses.
+fn md5_like_hash(input: &[u8]) -> [u8; 32] {
+    let mut state = [0u8; 32];
+    for (i, byte) in input.iter().enumerate() {
+        let idx = i % 32;
+        state[idx] = state[idx].wrapping_add(*byte).rotate_left(3);
+    }
+    state
 }
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use ethereum_boilerplate_shared::{get_network_by_chain_id, get_network_by_name, NetworkInfo};
 use std::env;
 use url::Url;
 
@@ -12,6 +13,61 @@ pub struct Config {
 
     /// URL базы данных (PostgreSQL)
     pub database_url: Option<String>,
+
+    /// Tuning for [`crate::middleware::RetryLayer`]: how many times to
+    /// retry a transient RPC failure and the exponential backoff bounds
+    /// between attempts.
+    pub retry: RetryConfig,
+
+    /// The network `ethereum_rpc_url` is expected to reach, selected by the
+    /// `NETWORK` env var (name or chain id) from `SUPPORTED_NETWORKS`.
+    /// `BlockchainService::new` asserts the endpoint's `eth_chainId`
+    /// matches this before serving any requests. `None` (the default, e.g.
+    /// a local anvil/geth node not in `SUPPORTED_NETWORKS`) skips the
+    /// check entirely.
+    pub network: Option<NetworkInfo>,
+
+    /// Extra `(network, rpc_url)` pairs from `NETWORK_RPC_URLS`, each
+    /// spun up as its own `BlockchainService` by `NetworkRegistry` so a
+    /// single server instance can route a request to whichever network it
+    /// asks for.
+    pub extra_networks: Vec<(String, Url)>,
+}
+
+/// Retry tuning read from the environment, with the same defaults
+/// [`crate::middleware::RetryLayer`] used before it was configurable.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self { max_attempts: 4, base_backoff_ms: 250, max_backoff_ms: 10_000 }
+    }
+}
+
+impl RetryConfig {
+    fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let max_attempts = env::var("RETRY_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.max_attempts);
+        let base_backoff_ms = env::var("RETRY_BASE_BACKOFF_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.base_backoff_ms);
+        let max_backoff_ms = env::var("RETRY_MAX_BACKOFF_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.max_backoff_ms);
+
+        Self { max_attempts, base_backoff_ms, max_backoff_ms }
+    }
 }
 
 // Кастомная реализация Debug для безопасности (не логируем секреты полностью)
@@ -21,6 +77,8 @@ impl std::fmt::Debug for Config {
             .field("ethereum_rpc_url", &self.ethereum_rpc_url.to_string())
             .field("moralis_api_key", &self.moralis_api_key.as_ref().map(|_| "***"))
             .field("database_url", &self.database_url.as_ref().map(|_| "***"))
+            .field("network", &self.network.as_ref().map(|n| &n.name))
+            .field("extra_networks", &self.extra_networks.iter().map(|(n, _)| n).collect::<Vec<_>>())
             .finish()
     }
 }
@@ -48,10 +106,21 @@ impl Config {
         let moralis_api_key = env::var("MORALIS_API_KEY").ok();
         let database_url = env::var("DATABASE_URL").ok();
 
+        // 3. Целевая сеть (опционально, для проверки eth_chainId)
+        let network = match env::var("NETWORK").ok() {
+            Some(selector) => Some(resolve_network(&selector)?),
+            None => None,
+        };
+
+        let extra_networks = parse_network_rpc_urls(env::var("NETWORK_RPC_URLS").ok().as_deref())?;
+
         Ok(Self {
             ethereum_rpc_url: rpc_url,
             moralis_api_key,
             database_url,
+            retry: RetryConfig::from_env(),
+            network,
+            extra_networks,
         })
     }
 
@@ -60,4 +129,39 @@ impl Config {
     pub fn db_url(&self) -> Option<&str> {
         self.database_url.as_deref()
     }
+}
+
+/// Resolves a `NETWORK` selector to an entry in `SUPPORTED_NETWORKS`,
+/// trying it first as a chain id (e.g. `"137"`) and then as a network name
+/// (e.g. `"polygon"`, matched case-insensitively by `get_network_by_name`).
+fn resolve_network(selector: &str) -> Result<NetworkInfo> {
+    if let Ok(chain_id) = selector.parse::<u64>() {
+        if let Some(network) = get_network_by_chain_id(chain_id) {
+            return Ok(network);
+        }
+    }
+
+    get_network_by_name(selector)
+        .with_context(|| format!("NETWORK '{}' is not one of the supported networks", selector))
+}
+
+/// Parses `NETWORK_RPC_URLS` as comma-separated `network=rpc_url` pairs
+/// (e.g. `"polygon=https://...,11155111=https://..."`), each `network`
+/// resolved the same way `NETWORK` is.
+fn parse_network_rpc_urls(raw: Option<&str>) -> Result<Vec<(String, Url)>> {
+    let Some(raw) = raw else { return Ok(Vec::new()) };
+
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (selector, url) = entry
+                .split_once('=')
+                .with_context(|| format!("NETWORK_RPC_URLS entry '{}' must be 'network=url'", entry))?;
+
+            resolve_network(selector)?;
+            let rpc_url = Url::parse(url).with_context(|| format!("Invalid RPC URL in NETWORK_RPC_URLS: {}", url))?;
+            Ok((selector.to_string(), rpc_url))
+        })
+        .collect()
 }
\ No newline at end of file
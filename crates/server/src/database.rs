@@ -1,12 +1,19 @@
 use crate::error::AppError;
+use chrono::{DateTime, Utc};
 use ethereum_boilerplate_shared::types::{Address, U256};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use sqlx::{postgres::PgPoolOptions, Pool, Postgres, Row};
+use std::collections::BTreeMap;
+use std::path::Path;
 use std::str::FromStr;
 use tracing::info;
 
 pub type DbPool = Pool<Postgres>;
 
+/// Default directory [`DatabaseService::new`] discovers migrations from.
+const MIGRATIONS_DIR: &str = "./migrations";
+
 pub struct DatabaseService {
     pool: DbPool,
 }
@@ -14,17 +21,156 @@ pub struct DatabaseService {
 impl DatabaseService {
     pub async fn new(database_url: &str) -> Result<Self, AppError> {
         info!("Connecting to database...");
-        
+
         let pool = PgPoolOptions::new()
             .max_connections(10)
             .connect(database_url)
             .await?;
 
-        // Run migrations
-        sqlx::migrate!("./migrations").run(&pool).await?;
+        let service = DatabaseService { pool };
+        service.apply_pending_migrations(Path::new(MIGRATIONS_DIR)).await?;
 
         info!("Database connected successfully");
-        Ok(DatabaseService { pool })
+        Ok(service)
+    }
+
+    /// Creates `schema_migrations` if it doesn't already exist.
+    async fn ensure_schema_migrations_table(&self) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS schema_migrations (
+                version BIGINT PRIMARY KEY,
+                name TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Every row currently recorded in `schema_migrations`, keyed by version.
+    async fn applied_migrations(&self) -> Result<BTreeMap<i64, AppliedMigration>, AppError> {
+        self.ensure_schema_migrations_table().await?;
+
+        let rows = sqlx::query(
+            "SELECT version, name, checksum, applied_at FROM schema_migrations ORDER BY version",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let version: i64 = row.get("version");
+                (
+                    version,
+                    AppliedMigration {
+                        name: row.get("name"),
+                        checksum: row.get("checksum"),
+                        applied_at: row.get("applied_at"),
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Applies every discovered migration whose version isn't recorded in
+    /// `schema_migrations` yet, in version order, each inside its own transaction followed by its
+    /// `schema_migrations` insert, so a failing migration leaves every
+    /// earlier one committed and recorded.
+    pub async fn apply_pending_migrations(&self, dir: &Path) -> Result<Vec<i64>, AppError> {
+        let migrations = discover_migrations(dir)?;
+        let applied = self.applied_migrations().await?;
+
+        let mut applied_versions = Vec::new();
+
+        for migration in migrations.into_iter().filter(|m| !applied.contains_key(&m.version)) {
+            let mut tx = self.pool.begin().await?;
+
+            sqlx::query(&migration.up_sql).execute(&mut *tx).await?;
+
+            sqlx::query(
+                "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES ($1, $2, $3, NOW())",
+            )
+            .bind(migration.version)
+            .bind(&migration.name)
+            .bind(&migration.checksum)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            info!("Applied migration {:04}_{}", migration.version, migration.name);
+            applied_versions.push(migration.version);
+        }
+
+        Ok(applied_versions)
+    }
+
+    /// Rolls back the `steps` most recently applied migrations (by version,
+    /// newest first), running each `.down.sql` and its `schema_migrations`
+    /// delete inside one transaction so a failed rollback leaves the
+    /// recorded history exactly as it was before the call.
+    pub async fn rollback(&self, dir: &Path, steps: usize) -> Result<Vec<i64>, AppError> {
+        if steps == 0 {
+            return Ok(Vec::new());
+        }
+
+        let migrations = discover_migrations(dir)?
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect::<BTreeMap<_, _>>();
+        let applied = self.applied_migrations().await?;
+
+        let to_revert: Vec<i64> = applied.keys().rev().take(steps).copied().collect();
+        let mut reverted = Vec::new();
+        let mut tx = self.pool.begin().await?;
+
+        for version in to_revert {
+            let migration = migrations.get(&version).ok_or_else(|| {
+                AppError::MigrationHistory(format!(
+                    "applied migration {} has no migration files on disk -- cannot roll back",
+                    version
+                ))
+            })?;
+
+            sqlx::query(&migration.down_sql).execute(&mut *tx).await?;
+            sqlx::query("DELETE FROM schema_migrations WHERE version = $1")
+                .bind(version)
+                .execute(&mut *tx)
+                .await?;
+
+            info!("Rolled back migration {:04}_{}", migration.version, migration.name);
+            reverted.push(version);
+        }
+
+        tx.commit().await?;
+        Ok(reverted)
+    }
+
+    /// Applied-vs-pending view: every discovered
+    /// migration, flagged with whether it's applied and whether its on-disk
+    /// checksum still matches what was recorded when it ran.
+    pub async fn migration_status(&self, dir: &Path) -> Result<Vec<MigrationStatus>, AppError> {
+        let migrations = discover_migrations(dir)?;
+        let applied = self.applied_migrations().await?;
+
+        Ok(migrations
+            .into_iter()
+            .map(|migration| {
+                let recorded = applied.get(&migration.version);
+                MigrationStatus {
+                    version: migration.version,
+                    name: migration.name,
+                    applied: recorded.is_some(),
+                    applied_at: recorded.map(|r| r.applied_at),
+                    checksum_mismatch: recorded.is_some_and(|r| r.checksum != migration.checksum),
+                }
+            })
+            .collect())
     }
 
     pub async fn save_nft_metadata(
@@ -176,4 +322,236 @@ impl DatabaseService {
 
         Ok(())
     }
+
+    /// p50/p95/p99 response time per endpoint since `since`, computed in SQL
+    /// with `percentile_cont` rather than pulling every row and sorting in
+    /// Rust.
+    pub async fn endpoint_latency_percentiles(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<EndpointLatency>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                endpoint,
+                COUNT(*) AS call_count,
+                percentile_cont(0.5) WITHIN GROUP (ORDER BY response_time_ms) AS p50_ms,
+                percentile_cont(0.95) WITHIN GROUP (ORDER BY response_time_ms) AS p95_ms,
+                percentile_cont(0.99) WITHIN GROUP (ORDER BY response_time_ms) AS p99_ms
+            FROM api_logs
+            WHERE created_at >= $1
+            GROUP BY endpoint
+            ORDER BY call_count DESC
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| EndpointLatency {
+                endpoint: row.get("endpoint"),
+                call_count: row.get("call_count"),
+                p50_ms: row.get("p50_ms"),
+                p95_ms: row.get("p95_ms"),
+                p99_ms: row.get("p99_ms"),
+            })
+            .collect())
+    }
+
+    /// The `limit` most-queried addresses since `since`, ordered by call
+    /// count descending. Skips rows with no address (internal/health-check
+    /// calls never bind one -- see [`Self::log_api_call`]).
+    pub async fn top_addresses(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<AddressCallCount>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT address, COUNT(*) AS call_count
+            FROM api_logs
+            WHERE created_at >= $1 AND address IS NOT NULL
+            GROUP BY address
+            ORDER BY call_count DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let address_bytes: &[u8] = row.get("address");
+                if address_bytes.len() != 20 {
+                    return Err(AppError::ParseError(format!(
+                        "stored address has {} bytes, expected 20",
+                        address_bytes.len()
+                    )));
+                }
+                let address = alloy::primitives::Address::from_slice(address_bytes);
+                Ok(AddressCallCount {
+                    address: format!("{:#x}", address),
+                    call_count: row.get("call_count"),
+                })
+            })
+            .collect()
+    }
+
+    /// Request volume since `since`, bucketed by hour.
+    pub async fn calls_over_time(&self, since: DateTime<Utc>) -> Result<Vec<HourlyCallVolume>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT date_trunc('hour', created_at) AS bucket, COUNT(*) AS call_count
+            FROM api_logs
+            WHERE created_at >= $1
+            GROUP BY bucket
+            ORDER BY bucket
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| HourlyCallVolume {
+                hour: row.get("bucket"),
+                call_count: row.get("call_count"),
+            })
+            .collect())
+    }
+}
+
+/// A `schema_migrations` row as read back from the database.
+struct AppliedMigration {
+    name: String,
+    checksum: String,
+    applied_at: DateTime<Utc>,
+}
+
+/// One discovered migration: a paired `NNNN_name.up.sql`/`NNNN_name.down.sql`
+/// file on disk. `checksum` is the up-file's SHA-256, compared against what
+/// was recorded at apply time to catch an edited migration after the fact.
+#[derive(Debug, Clone)]
+struct Migration {
+    version: i64,
+    name: String,
+    up_sql: String,
+    down_sql: String,
+    checksum: String,
+}
+
+/// Applied-vs-pending row returned by [`DatabaseService::migration_status`].
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub name: String,
+    pub applied: bool,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub checksum_mismatch: bool,
+}
+
+/// One row of [`DatabaseService::endpoint_latency_percentiles`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EndpointLatency {
+    pub endpoint: String,
+    pub call_count: i64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+}
+
+/// One row of [`DatabaseService::top_addresses`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AddressCallCount {
+    pub address: String,
+    pub call_count: i64,
+}
+
+/// One row of [`DatabaseService::calls_over_time`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HourlyCallVolume {
+    pub hour: DateTime<Utc>,
+    pub call_count: i64,
+}
+
+#[derive(Default)]
+struct PartialMigration {
+    name: Option<String>,
+    up_sql: Option<String>,
+    down_sql: Option<String>,
+}
+
+/// Reads `dir` for `NNNN_name.up.sql`/`NNNN_name.down.sql` pairs, sorted by
+/// version. An `.up.sql` with no matching `.down.sql` (or vice versa) is a
+/// migration this engine can't safely track, so it's an error rather than a
+/// silently-forward-only migration.
+fn discover_migrations(dir: &Path) -> Result<Vec<Migration>, AppError> {
+    let mut partials: BTreeMap<i64, PartialMigration> = BTreeMap::new();
+
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| AppError::MigrationHistory(format!("reading {}: {}", dir.display(), e)))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| AppError::MigrationHistory(e.to_string()))?;
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        let (stem, is_up) = if let Some(stem) = file_name.strip_suffix(".up.sql") {
+            (stem, true)
+        } else if let Some(stem) = file_name.strip_suffix(".down.sql") {
+            (stem, false)
+        } else {
+            continue;
+        };
+
+        let Some((version_str, name)) = stem.split_once('_') else {
+            return Err(AppError::MigrationHistory(format!(
+                "malformed migration filename (expected NNNN_name.{{up,down}}.sql): {}",
+                file_name
+            )));
+        };
+        let version: i64 = version_str.parse().map_err(|_| {
+            AppError::MigrationHistory(format!("malformed migration version in filename: {}", file_name))
+        })?;
+
+        let sql = std::fs::read_to_string(&path)
+            .map_err(|e| AppError::MigrationHistory(format!("reading {}: {}", path.display(), e)))?;
+
+        let partial = partials.entry(version).or_default();
+        partial.name = Some(name.to_string());
+        if is_up {
+            partial.up_sql = Some(sql);
+        } else {
+            partial.down_sql = Some(sql);
+        }
+    }
+
+    partials
+        .into_iter()
+        .map(|(version, partial)| {
+            let name = partial.name.unwrap_or_default();
+            let up_sql = partial.up_sql.ok_or_else(|| {
+                AppError::MigrationHistory(format!(
+                    "migration {:04}_{} has a down.sql but no matching up.sql",
+                    version, name
+                ))
+            })?;
+            let down_sql = partial.down_sql.ok_or_else(|| {
+                AppError::MigrationHistory(format!(
+                    "migration {:04}_{} has no matching down.sql",
+                    version, name
+                ))
+            })?;
+            let checksum = format!("{:x}", Sha256::digest(up_sql.as_bytes()));
+
+            Ok(Migration { version, name, up_sql, down_sql, checksum })
+        })
+        .collect()
 }
@@ -34,6 +34,20 @@ pub enum AppError {
 
     #[error("Migration error: {0}")]
     Migration(#[from] sqlx::migrate::MigrateError),
+
+    /// Raised by [`crate::database`]'s own reversible-migration engine --
+    /// a malformed/unpaired `.up.sql`/`.down.sql` file, an unreadable
+    /// migrations directory, or an applied version with no migration files
+    /// on disk to roll back. Distinct from [`AppError::Migration`], which only wraps
+    /// `sqlx::migrate!`'s own (forward-only) migrator.
+    #[error("Migration history error: {0}")]
+    MigrationHistory(String),
+
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+
+    #[error("Contract deployment error: {0}")]
+    ContractError(String),
 }
 
 /// Унифицированный JSON-ответ для фронтенда
@@ -69,13 +83,20 @@ impl IntoResponse for AppError {
             AppError::InvalidAddress(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
             AppError::ParseError(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            // A reverted creation or missing code-at-address is a fact about
+            // the submitted bytecode/constructor args, not an infra leak, so
+            // it's safe to show the client what went wrong.
+            AppError::ContractError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.clone()),
 
             // Ошибки инфраструктуры: скрываем подробности реализации
             AppError::EthereumProvider(_) => (StatusCode::BAD_GATEWAY, "Blockchain node connection failure".into()),
             AppError::HttpClient(_) => (StatusCode::BAD_GATEWAY, "External API service unavailable".into()),
-            AppError::Database(_) | AppError::Migration(_) => {
+            AppError::Database(_) | AppError::Migration(_) | AppError::MigrationHistory(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Database operation failed".into())
             }
+            AppError::ConfigurationError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Server configuration error".into())
+            }
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "An unexpected error occurred".into()),
         };
 
@@ -1,11 +1,13 @@
 use anyhow::Result;
 use axum::{
+    extract::State,
     http::StatusCode,
     response::Json,
     routing::{get, Router},
 };
 use serde_json::{json, Value};
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tower_http::cors::CorsLayer;
 use tracing::{info, level_filters::LevelFilter};
 use tracing_subscriber::EnvFilter;
@@ -14,11 +16,14 @@ mod api;
 mod blockchain;
 mod config;
 mod error;
+mod middleware;
 mod models;
+mod registry;
+mod rpc;
 
-use api::{balances, transfers, transactions};
+use api::{balances, transfers, transactions, contracts};
 use config::Config;
-use blockchain::BlockchainService;
+use registry::NetworkRegistry;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -39,15 +44,17 @@ async fn main() -> Result<()> {
     info!("RPC URL: {}", config.ethereum_rpc_url);
 
     // Create router
-    let blockchain_service = std::sync::Arc::new(BlockchainService::new(config)?);
-    
+    let registry = Arc::new(NetworkRegistry::from_config(config).await?);
+
     let app = Router::new()
         .route("/", get(health_check))
         .nest("/api/balances", balances::routes())
         .nest("/api/transfers", transfers::routes())
         .nest("/api/transactions", transactions::routes())
+        .nest("/api", contracts::routes())
+        .merge(rpc::routes())
         .layer(CorsLayer::permissive())
-        .with_state(blockchain_service);
+        .with_state(registry);
 
     // Start server
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
@@ -59,9 +66,17 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn health_check() -> Result<Json<Value>, StatusCode> {
+async fn health_check(State(registry): State<Arc<NetworkRegistry>>) -> Result<Json<Value>, StatusCode> {
+    let service = registry.default_service();
+    let chain_id = service.chain_id().await.map_err(|e| {
+        tracing::error!("Health check failed to reach the configured RPC endpoint: {}", e);
+        StatusCode::SERVICE_UNAVAILABLE
+    })?;
+
     Ok(Json(json!({
         "status": "ok",
-        "message": "Ethereum Boilerplate Rust API is running"
+        "message": "Ethereum Boilerplate Rust API is running",
+        "chain_id": chain_id,
+        "node_client": format!("{:?}", service.node_client())
     })))
 }
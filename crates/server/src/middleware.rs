@@ -0,0 +1,520 @@
+//! Composable middleware stack for [`crate::blockchain::BlockchainService`],
+//! mirroring the stackable provider pattern ethers/alloy-style libraries use
+//! (Provider -> Retry -> NonceManager -> GasOracle -> Signer): each layer
+//! implements [`ProviderLike`] and delegates the reads it doesn't care about
+//! to the layer underneath, overriding only what it's responsible for.
+//! [`BlockchainServiceBuilder`] stacks these opt-in so a caller only pays for
+//! the layers it asks for.
+//!
+//! This is the foundation for moving the `interact` CLI command's
+//! transaction-sending out of its `cargo run --bin interact` subprocess
+//! shell-out and into typed in-process calls: the nonce manager keeps
+//! concurrent sends from colliding on the same on-chain nonce, the gas
+//! oracle feeds them a live fee quote, and the signer layer is where the
+//! key that actually authorizes a transaction will live.
+
+use crate::blockchain::{BlockchainService, FeeEstimator};
+use crate::config::{Config, RetryConfig};
+use crate::error::AppError;
+use alloy::primitives::{Address, U256};
+use alloy::providers::{Provider, ProviderBuilder, RootProvider};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::Signer;
+use alloy::transports::http::{Client, Http};
+use ethereum_boilerplate_shared::GasStrategy;
+use rand::Rng;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn map_eth_err<E: std::fmt::Display>(e: E) -> AppError {
+    AppError::EthereumProvider(e.to_string())
+}
+
+/// Async read surface shared by the bare provider and every layer stacked on
+/// top of it by [`BlockchainServiceBuilder`].
+pub trait ProviderLike: Send + Sync {
+    fn get_chain_id(&self) -> impl Future<Output = Result<u64, AppError>> + Send;
+    fn get_block_number(&self) -> impl Future<Output = Result<u64, AppError>> + Send;
+    fn get_gas_price(&self) -> impl Future<Output = Result<U256, AppError>> + Send;
+    fn get_transaction_count(&self, address: Address) -> impl Future<Output = Result<u64, AppError>> + Send;
+}
+
+impl ProviderLike for RootProvider<Http<Client>> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        Provider::get_chain_id(self).await.map_err(map_eth_err)
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        Provider::get_block_number(self).await.map_err(map_eth_err)
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        let price = Provider::get_gas_price(self).await.map_err(map_eth_err)?;
+        Ok(U256::from(price))
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        Provider::get_transaction_count(self, address).await.map_err(map_eth_err)
+    }
+}
+
+impl<T: ProviderLike> ProviderLike for Arc<T> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        (**self).get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        (**self).get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        (**self).get_gas_price().await
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        (**self).get_transaction_count(address).await
+    }
+}
+
+/// Whether a failed RPC call is worth retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryClass {
+    Transient,
+    Fatal,
+}
+
+/// Classifies a failed RPC call and extracts a provider-specified wait
+/// before the next attempt, if any. Kept pluggable behind a trait (rather
+/// than baked into [`RetryLayer`]) so different providers can layer their
+/// own error-classification logic over the shared HTTP-status/JSON-RPC-code
+/// defaults — Alchemy and Infura report rate limiting through different
+/// messages and codes.
+pub trait RetryPolicy: Send + Sync {
+    /// Classifies `err` as worth retrying (HTTP 429/5xx, JSON-RPC rate
+    /// limiting, timeouts) or fatal (everything else, e.g. a bad
+    /// request/revert).
+    fn classify(&self, err: &AppError) -> RetryClass;
+
+    /// A provider-specified wait before the next attempt (e.g. a
+    /// `Retry-After` header), if the failure carried one. `RetryLayer` uses
+    /// this instead of its own computed backoff when present. Defaults to
+    /// none.
+    fn retry_after(&self, _err: &AppError) -> Option<Duration> {
+        None
+    }
+}
+
+/// Scans `message` for a bare `retry-after: <seconds>`-style hint. Our
+/// transport only surfaces errors as formatted strings, so this is a
+/// best-effort scan rather than a real header read.
+fn parse_retry_after(message: &str) -> Option<Duration> {
+    let lower = message.to_lowercase();
+    let after = lower.split("retry-after").nth(1)?;
+    let digits: String = after.chars().skip_while(|c| !c.is_ascii_digit()).take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Classifies a message by the rate-limit/timeout substrings every provider
+/// shares, falling back to treating any embedded `5xx` status as transient
+/// and any other embedded `4xx` status as fatal.
+fn classify_common(message: &str) -> RetryClass {
+    let lower = message.to_lowercase();
+    if lower.contains("rate limit") || lower.contains("-32005") || lower.contains("timeout") || lower.contains("timed out") {
+        return RetryClass::Transient;
+    }
+
+    match find_http_status(&lower) {
+        Some(429) => RetryClass::Transient,
+        Some(status) if (500..600).contains(&status) => RetryClass::Transient,
+        Some(status) if (400..500).contains(&status) => RetryClass::Fatal,
+        _ => RetryClass::Fatal,
+    }
+}
+
+/// Finds the first bare 3-digit HTTP status code (4xx/5xx) in `message`.
+fn find_http_status(message: &str) -> Option<u16> {
+    let bytes = message.as_bytes();
+    for (i, window) in bytes.windows(3).enumerate() {
+        if window.iter().all(u8::is_ascii_digit) {
+            let before_ok = i == 0 || !bytes[i - 1].is_ascii_digit();
+            let after_ok = i + 3 == bytes.len() || !bytes[i + 3].is_ascii_digit();
+            if before_ok && after_ok {
+                if let Ok(status) = std::str::from_utf8(window).unwrap().parse::<u16>() {
+                    if (400..600).contains(&status) {
+                        return Some(status);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Default classification: HTTP 429/5xx, JSON-RPC "rate limit"/-32005, and
+/// timeouts are transient; every other 4xx is fatal. No provider-specific
+/// `Retry-After` parsing beyond the best-effort string scan.
+pub struct DefaultRetryPolicy;
+
+impl RetryPolicy for DefaultRetryPolicy {
+    fn classify(&self, err: &AppError) -> RetryClass {
+        classify_common(&err.to_string())
+    }
+
+    fn retry_after(&self, err: &AppError) -> Option<Duration> {
+        parse_retry_after(&err.to_string())
+    }
+}
+
+/// Alchemy also rate-limits via a `"compute units"` message and JSON-RPC
+/// code `-32029`, on top of the common 429/5xx/-32005 set.
+pub struct AlchemyRetryPolicy;
+
+impl RetryPolicy for AlchemyRetryPolicy {
+    fn classify(&self, err: &AppError) -> RetryClass {
+        let message = err.to_string().to_lowercase();
+        if message.contains("compute units") || message.contains("-32029") {
+            return RetryClass::Transient;
+        }
+        classify_common(&message)
+    }
+
+    fn retry_after(&self, err: &AppError) -> Option<Duration> {
+        parse_retry_after(&err.to_string())
+    }
+}
+
+/// Infura also rate-limits via a `"daily request count exceeded"` message
+/// and JSON-RPC code `-32097`, on top of the common 429/5xx/-32005 set.
+pub struct InfuraRetryPolicy;
+
+impl RetryPolicy for InfuraRetryPolicy {
+    fn classify(&self, err: &AppError) -> RetryClass {
+        let message = err.to_string().to_lowercase();
+        if message.contains("daily request count exceeded") || message.contains("-32097") {
+            return RetryClass::Transient;
+        }
+        classify_common(&message)
+    }
+
+    fn retry_after(&self, err: &AppError) -> Option<Duration> {
+        parse_retry_after(&err.to_string())
+    }
+}
+
+/// Retries a transient `inner` failure with exponential backoff plus
+/// jitter, honoring a policy-reported `Retry-After` wait in place of the
+/// computed backoff when present; anything fatal (or the final attempt) is
+/// surfaced immediately. Delegates every call to `inner`, so it can sit
+/// anywhere in the stack.
+pub struct RetryLayer<L, P = DefaultRetryPolicy> {
+    inner: L,
+    policy: P,
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<L: ProviderLike> RetryLayer<L, DefaultRetryPolicy> {
+    pub fn new(inner: L) -> Self {
+        Self::with_policy(inner, DefaultRetryPolicy)
+    }
+}
+
+impl<L: ProviderLike, P: RetryPolicy> RetryLayer<L, P> {
+    pub fn with_policy(inner: L, policy: P) -> Self {
+        Self {
+            inner,
+            policy,
+            max_attempts: 4,
+            base_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    pub fn configured(mut self, config: RetryConfig) -> Self {
+        self.max_attempts = config.max_attempts;
+        self.base_backoff = Duration::from_millis(config.base_backoff_ms);
+        self.max_backoff = Duration::from_millis(config.max_backoff_ms);
+        self
+    }
+
+    async fn retry<T, F, Fut>(&self, op: F) -> Result<T, AppError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, AppError>>,
+    {
+        let mut delay = self.base_backoff;
+        let mut last_err = AppError::EthereumProvider("RetryLayer: no attempts were made".to_string());
+
+        for attempt in 0..self.max_attempts {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let is_last_attempt = attempt + 1 == self.max_attempts;
+                    let class = self.policy.classify(&e);
+                    let retry_after = self.policy.retry_after(&e);
+                    last_err = e;
+
+                    if class == RetryClass::Fatal || is_last_attempt {
+                        return Err(last_err);
+                    }
+
+                    let wait = match retry_after {
+                        Some(wait) => wait,
+                        None => {
+                            let jitter_ms = rand::thread_rng().gen_range(0..=delay.as_millis() as u64);
+                            delay + Duration::from_millis(jitter_ms)
+                        }
+                    };
+                    sleep(wait).await;
+                    delay = (delay * 2).min(self.max_backoff);
+                }
+            }
+        }
+
+        Err(last_err)
+    }
+}
+
+impl<L: ProviderLike, P: RetryPolicy> ProviderLike for RetryLayer<L, P> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        self.retry(|| self.inner.get_chain_id()).await
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        self.retry(|| self.inner.get_block_number()).await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        self.retry(|| self.inner.get_gas_price()).await
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        self.retry(|| self.inner.get_transaction_count(address)).await
+    }
+}
+
+/// Caches the account nonce locally and hands out `nonce + 1` for each
+/// dispatched transaction, so a batch of concurrent sends doesn't collide on
+/// the same on-chain nonce. Delegates every `ProviderLike` read to `inner`
+/// unchanged.
+pub struct NonceManagerLayer<L> {
+    inner: L,
+    base: Arc<RootProvider<Http<Client>>>,
+    cached: AtomicU64,
+    primed: AtomicBool,
+}
+
+impl<L: ProviderLike> NonceManagerLayer<L> {
+    pub fn new(inner: L, base: Arc<RootProvider<Http<Client>>>) -> Self {
+        Self {
+            inner,
+            base,
+            cached: AtomicU64::new(0),
+            primed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the next nonce to use, priming the cache from
+    /// `eth_getTransactionCount` on first use.
+    pub async fn next_nonce(&self, address: Address) -> Result<u64, AppError> {
+        if !self.primed.swap(true, Ordering::SeqCst) {
+            let onchain = Provider::get_transaction_count(&*self.base, address).await.map_err(map_eth_err)?;
+            self.cached.store(onchain, Ordering::SeqCst);
+            return Ok(onchain);
+        }
+
+        Ok(self.cached.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Drops the local cache, forcing the next call to re-fetch from the
+    /// node. Use this after a nonce-related RPC rejection (e.g. "nonce too
+    /// low").
+    pub fn reset(&self) {
+        self.primed.store(false, Ordering::SeqCst);
+        self.cached.store(0, Ordering::SeqCst);
+    }
+}
+
+impl<L: ProviderLike> ProviderLike for NonceManagerLayer<L> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        self.inner.get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        self.inner.get_gas_price().await
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        self.inner.get_transaction_count(address).await
+    }
+}
+
+/// Overrides `get_gas_price()` with the `Standard`-strategy `max_fee_per_gas`
+/// from a [`FeeEstimator`] (an `eth_feeHistory`-based quote) instead of the
+/// inner provider's `eth_gasPrice`. Every other read passes through
+/// unchanged.
+pub struct GasOracleLayer<L> {
+    inner: L,
+    fee_estimator: Arc<FeeEstimator>,
+}
+
+impl<L: ProviderLike> GasOracleLayer<L> {
+    pub fn new(inner: L, fee_estimator: Arc<FeeEstimator>) -> Self {
+        Self { inner, fee_estimator }
+    }
+}
+
+impl<L: ProviderLike> ProviderLike for GasOracleLayer<L> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        self.inner.get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        let fees = self.fee_estimator.estimate(GasStrategy::Standard).await?;
+        Ok(fees.max_fee_per_gas)
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        self.inner.get_transaction_count(address).await
+    }
+}
+
+/// Holds the `PrivateKeySigner` that will authorize transactions dispatched
+/// through this stack. Doesn't alter any read; `signer()`/`address()` are
+/// the extension points the in-process `interact` replacement will build on.
+pub struct SignerLayer<L> {
+    inner: L,
+    signer: PrivateKeySigner,
+}
+
+impl<L: ProviderLike> SignerLayer<L> {
+    pub fn new(inner: L, signer: PrivateKeySigner) -> Self {
+        Self { inner, signer }
+    }
+
+    pub fn signer(&self) -> &PrivateKeySigner {
+        &self.signer
+    }
+
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+}
+
+impl<L: ProviderLike> ProviderLike for SignerLayer<L> {
+    async fn get_chain_id(&self) -> Result<u64, AppError> {
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64, AppError> {
+        self.inner.get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256, AppError> {
+        self.inner.get_gas_price().await
+    }
+
+    async fn get_transaction_count(&self, address: Address) -> Result<u64, AppError> {
+        self.inner.get_transaction_count(address).await
+    }
+}
+
+/// Builds a [`BlockchainService`] by stacking opt-in middleware layers on a
+/// base HTTP provider. Layers wrap in whatever order they're called, from
+/// innermost (closest to the base transport) to outermost:
+///
+/// ```ignore
+/// let service = BlockchainServiceBuilder::new(config)?
+///     .with_retry()
+///     .with_nonce_manager()
+///     .with_gas_oracle()
+///     .with_signer(signer)
+///     .build();
+/// ```
+pub struct BlockchainServiceBuilder<L> {
+    layer: L,
+    provider: Arc<RootProvider<Http<Client>>>,
+    fee_estimator: Arc<FeeEstimator>,
+    retry_config: RetryConfig,
+}
+
+impl BlockchainServiceBuilder<Arc<RootProvider<Http<Client>>>> {
+    pub fn new(config: Config) -> Result<Self, AppError> {
+        let provider = Arc::new(ProviderBuilder::new().on_http(config.ethereum_rpc_url.clone()));
+        let fee_estimator = Arc::new(FeeEstimator::new(provider.clone()));
+        let retry_config = config.retry;
+
+        Ok(Self { layer: provider.clone(), provider, fee_estimator, retry_config })
+    }
+}
+
+impl<L: ProviderLike> BlockchainServiceBuilder<L> {
+    /// Wraps the stack in [`RetryLayer`] using [`DefaultRetryPolicy`] and
+    /// the `Config`-supplied retry tuning. Use [`Self::with_retry_policy`]
+    /// to plug in a provider-specific policy instead.
+    pub fn with_retry(self) -> BlockchainServiceBuilder<RetryLayer<L>> {
+        BlockchainServiceBuilder {
+            layer: RetryLayer::new(self.layer).configured(self.retry_config),
+            provider: self.provider,
+            fee_estimator: self.fee_estimator,
+            retry_config: self.retry_config,
+        }
+    }
+
+    /// Wraps the stack in [`RetryLayer`] using a caller-supplied
+    /// [`RetryPolicy`] (e.g. [`AlchemyRetryPolicy`]/[`InfuraRetryPolicy`]
+    /// for a provider whose rate-limit responses the default policy
+    /// doesn't recognize), still tuned from the `Config`-supplied retry
+    /// settings.
+    pub fn with_retry_policy<P: RetryPolicy>(self, policy: P) -> BlockchainServiceBuilder<RetryLayer<L, P>> {
+        BlockchainServiceBuilder {
+            layer: RetryLayer::with_policy(self.layer, policy).configured(self.retry_config),
+            provider: self.provider,
+            fee_estimator: self.fee_estimator,
+            retry_config: self.retry_config,
+        }
+    }
+
+    pub fn with_nonce_manager(self) -> BlockchainServiceBuilder<NonceManagerLayer<L>> {
+        BlockchainServiceBuilder {
+            layer: NonceManagerLayer::new(self.layer, self.provider.clone()),
+            provider: self.provider,
+            fee_estimator: self.fee_estimator,
+            retry_config: self.retry_config,
+        }
+    }
+
+    pub fn with_gas_oracle(self) -> BlockchainServiceBuilder<GasOracleLayer<L>> {
+        BlockchainServiceBuilder {
+            layer: GasOracleLayer::new(self.layer, self.fee_estimator.clone()),
+            provider: self.provider,
+            fee_estimator: self.fee_estimator,
+            retry_config: self.retry_config,
+        }
+    }
+
+    pub fn with_signer(self, signer: PrivateKeySigner) -> BlockchainServiceBuilder<SignerLayer<L>> {
+        BlockchainServiceBuilder {
+            layer: SignerLayer::new(self.layer, signer),
+            provider: self.provider,
+            fee_estimator: self.fee_estimator,
+            retry_config: self.retry_config,
+        }
+    }
+
+    pub fn build(self) -> BlockchainService<L> {
+        BlockchainService::from_parts(self.provider, self.fee_estimator, self.layer)
+    }
+}
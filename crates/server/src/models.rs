@@ -1,4 +1,4 @@
-use alloy::primitives::{Address, U256};
+use alloy::primitives::{Address, Bytes, U256};
 use alloy::primitives::aliases::B256;
 use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
@@ -49,6 +49,16 @@ pub struct ERC20Balance {
     pub decimals: u8,
 }
 
+/// Per-token-id balance from an ERC-1155 `balanceOfBatch` call; see
+/// [`crate::blockchain::BlockchainService::get_erc1155_balances`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ERC1155Balance {
+    #[serde(with = "u256_ser")]
+    pub token_id: U256,
+    #[serde(with = "u256_ser")]
+    pub balance: U256,
+}
+
 // --- Transactions and Transfers ---------------------------------------------
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +107,44 @@ pub struct ERC20Transfer {
     pub timestamp: Option<DateTime<Utc>>,
 }
 
+// --- Transaction Tracing ----------------------------------------------------
+
+/// One frame of a `debug_traceTransaction` `callTracer` call tree; see
+/// [`crate::blockchain::BlockchainService::trace_transaction`]. Mirrors
+/// geth's `callTracer` output shape (`type`/`from`/`to`/`value`/`gasUsed`/
+/// `input`/`output`/`error`/`calls`), but with `value`/`gas_used` re-encoded
+/// through [`u256_ser`] like every other amount in this module, instead of
+/// the raw hex strings the RPC itself returns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallFrame {
+    pub call_type: String,
+    pub from: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    #[serde(default, with = "u256_ser_option", skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    #[serde(with = "u256_ser")]
+    pub gas_used: U256,
+    pub input: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// ABI-decoded `Error(string)` message, when `output` carries one (see
+    /// `blockchain::decode_revert_reason`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    #[serde(default)]
+    pub calls: Vec<CallFrame>,
+}
+
+/// Result of [`crate::blockchain::BlockchainService::trace_transaction`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceResult {
+    pub transaction_hash: B256,
+    pub root: CallFrame,
+}
+
 // --- Wallet Information --------------------------------------------------
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +161,133 @@ pub struct WalletInfo {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AddressQuery {
     pub address: Address,
+    /// Routes the query to that chain's `BlockchainService` instead of the
+    /// server's default network; takes precedence over `network` if both
+    /// are given. See `registry::NetworkRegistry::resolve`.
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    /// Same as `chain_id`, but by name (e.g. `"polygon"`).
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+/// Query for [`crate::blockchain::BlockchainService::get_transfer_history`]:
+/// `from_block`/`to_block` default to `BlockchainService`'s usual lookback
+/// window when omitted, same as `TransactionsQuery`'s `limit`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransfersQuery {
+    pub address: Address,
+    #[serde(default)]
+    pub from_block: Option<u64>,
+    #[serde(default)]
+    pub to_block: Option<u64>,
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+/// Query for [`crate::blockchain::BlockchainService::get_erc1155_balances`]:
+/// `token_ids` is a comma-separated list (e.g. `"1,2,3"`), matching how
+/// `config::parse_network_rpc_urls` already parses comma-separated values
+/// out of a single query/env field rather than relying on repeated-key
+/// array deserialization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Erc1155BalanceQuery {
+    pub token_address: Address,
+    pub address: Address,
+    pub token_ids: String,
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+/// Query for [`crate::blockchain::BlockchainService::estimate_fees`]: which
+/// strategy to price at, plus the same chain routing fields every other
+/// query DTO in this module carries.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GasEstimateQuery {
+    #[serde(default)]
+    pub gas_strategy: ethereum_boilerplate_shared::GasStrategy,
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasEstimateResult {
+    #[serde(with = "u256_ser")]
+    pub max_fee_per_gas: U256,
+    #[serde(with = "u256_ser")]
+    pub max_priority_fee_per_gas: U256,
+}
+
+// --- Contract Deployment / Token Actions -----------------------------------
+//
+// Request/response types shared by the REST handlers in `api::contracts`
+// and the JSON-RPC dispatcher in `rpc`, so both surfaces stay in sync.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployContractParams {
+    pub contract_type: String,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployContractResult {
+    pub address: Address,
+    pub transaction_hash: B256,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintTokensParams {
+    pub contract_address: Address,
+    pub amount: String,
+    #[serde(default)]
+    pub gas_strategy: ethereum_boilerplate_shared::GasStrategy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferTokensParams {
+    pub contract_address: Address,
+    pub to: Address,
+    pub amount: String,
+    #[serde(default)]
+    pub gas_strategy: ethereum_boilerplate_shared::GasStrategy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionResult {
+    pub success: bool,
+    pub transaction_hash: B256,
+    #[serde(with = "u256_ser")]
+    pub max_fee_per_gas: U256,
+    #[serde(with = "u256_ser")]
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Params for [`crate::blockchain::BlockchainService::deploy_bytecode`]/
+/// [`crate::blockchain::BlockchainService::deploy_reproducible`]: raw
+/// `init_code` (constructor args already ABI-encoded and appended by the
+/// caller, same as a raw `eth_sendTransaction` with no `to`) plus the EOA
+/// whose nonce the deployment transaction is sent from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployBytecodeParams {
+    pub init_code: alloy::primitives::Bytes,
+    pub deployer: Address,
+}
+
+/// Result of an in-process bytecode deployment: the resolved contract
+/// address alongside the same [`ethereum_boilerplate_shared::ContractInteractionResult`]
+/// shape `interact`-style contract calls already report, so a caller
+/// handling both doesn't need two different result formats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployedContractInfo {
+    pub address: Address,
+    pub interaction: ethereum_boilerplate_shared::ContractInteractionResult,
 }
 
 /// Helper module for Option<U256>
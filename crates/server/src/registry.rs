@@ -0,0 +1,81 @@
+//! Holds one [`BlockchainService`] per configured network, keyed by chain
+//! id, so a single server instance can route a request to whichever
+//! network it asks for instead of assuming every caller wants the primary
+//! network from `Config`.
+
+use crate::blockchain::BlockchainService;
+use crate::config::Config;
+use crate::error::AppError;
+use ethereum_boilerplate_shared::get_network_by_name;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct NetworkRegistry {
+    services: HashMap<u64, Arc<BlockchainService>>,
+    default_chain_id: u64,
+}
+
+impl NetworkRegistry {
+    /// Builds the primary `BlockchainService` from `config`, plus one more
+    /// per `config.extra_networks` entry, each keyed by its own
+    /// `eth_chainId`.
+    pub async fn from_config(config: Config) -> Result<Self, AppError> {
+        let extra_networks = config.extra_networks.clone();
+        let base = config.clone();
+
+        let primary = Arc::new(BlockchainService::new(config).await?);
+        let default_chain_id = primary.chain_id().await?;
+
+        let mut services = HashMap::new();
+        services.insert(default_chain_id, primary);
+
+        for (selector, rpc_url) in extra_networks {
+            let network = selector
+                .parse::<u64>()
+                .ok()
+                .and_then(ethereum_boilerplate_shared::get_network_by_chain_id)
+                .or_else(|| get_network_by_name(&selector))
+                .ok_or_else(|| AppError::ConfigurationError(format!("Unknown network in NETWORK_RPC_URLS: {}", selector)))?;
+
+            let network_config = Config {
+                ethereum_rpc_url: rpc_url,
+                network: Some(network),
+                extra_networks: Vec::new(),
+                ..base.clone()
+            };
+
+            let service = Arc::new(BlockchainService::new(network_config).await?);
+            let chain_id = service.chain_id().await?;
+            services.insert(chain_id, service);
+        }
+
+        Ok(Self { services, default_chain_id })
+    }
+
+    /// The `BlockchainService` for the default network (the one
+    /// `Config::ethereum_rpc_url` pointed at), for callers that don't yet
+    /// accept a `chain_id`/`network` selector.
+    pub fn default_service(&self) -> &Arc<BlockchainService> {
+        self.services.get(&self.default_chain_id).expect("default_chain_id is always inserted in from_config")
+    }
+
+    /// Resolves a request's optional `chain_id`/`network` selectors to the
+    /// matching `BlockchainService`, falling back to the default network
+    /// (the one `Config::ethereum_rpc_url` pointed at) when neither is
+    /// given.
+    pub fn resolve(&self, chain_id: Option<u64>, network: Option<&str>) -> Result<&Arc<BlockchainService>, AppError> {
+        let chain_id = match (chain_id, network) {
+            (Some(id), _) => id,
+            (None, Some(name)) => {
+                get_network_by_name(name)
+                    .ok_or_else(|| AppError::ConfigurationError(format!("Unknown network: {}", name)))?
+                    .chain_id
+            }
+            (None, None) => self.default_chain_id,
+        };
+
+        self.services
+            .get(&chain_id)
+            .ok_or_else(|| AppError::ConfigurationError(format!("No provider configured for chain id {}", chain_id)))
+    }
+}
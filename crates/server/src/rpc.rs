@@ -0,0 +1,142 @@
+//! JSON-RPC surface over the same socket as the REST API.
+//!
+//! Exposes the same operations as `api::contracts`/`api::balances`/
+//! `api::transactions` as named RPC methods (`get_balance`,
+//! `deploy_contract`, `mint_tokens`, `transfer_tokens`,
+//! `transaction_history`) for programmatic/scripted control, the way
+//! long-running node/swap daemons surface an RPC control plane alongside
+//! their HTTP API. Every method dispatches into the same `BlockchainService`
+//! methods the REST handlers call, so the two surfaces can never drift.
+
+use crate::api::transactions::TransactionsQuery;
+use crate::blockchain::BlockchainService;
+use crate::models::{AddressQuery, DeployContractParams, MintTokensParams, TransferTokensParams};
+use crate::registry::NetworkRegistry;
+use axum::{extract::State, response::Json, routing::post, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct RpcRequest {
+    #[serde(default)]
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RpcErrorBody {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RpcResponse {
+    pub id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcErrorBody>,
+}
+
+impl RpcResponse {
+    fn ok(id: Value, result: Value) -> Self {
+        Self { id, result: Some(result), error: None }
+    }
+
+    fn err(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self { id, result: None, error: Some(RpcErrorBody { code, message: message.into() }) }
+    }
+}
+
+pub fn routes() -> Router<Arc<NetworkRegistry>> {
+    Router::new().route("/rpc", post(handle_rpc))
+}
+
+async fn handle_rpc(
+    State(registry): State<Arc<NetworkRegistry>>,
+    Json(request): Json<RpcRequest>,
+) -> Json<RpcResponse> {
+    Json(dispatch(&registry, request).await)
+}
+
+/// Routes a single RPC request to the matching `BlockchainService` call and
+/// serializes its result, mirroring the REST handlers method-for-method.
+/// `get_balance`/`transaction_history` honor the request's `chain_id`/
+/// `network` the same way their REST counterparts do; `deploy_contract`/
+/// `mint_tokens`/`transfer_tokens` run against the registry's default
+/// network, matching `api::contracts`.
+async fn dispatch(registry: &NetworkRegistry, request: RpcRequest) -> RpcResponse {
+    let RpcRequest { id, method, params } = request;
+    let default = registry.default_service().clone();
+
+    let result = match method.as_str() {
+        "get_balance" => parse_params::<AddressQuery>(&params)
+            .and_then(|p| Ok((resolve(registry, p.chain_id, p.network.as_deref())?, p)))
+            .and_then_async(|(b, p)| async move { b.get_wallet_info(p.address).await })
+            .await,
+        "deploy_contract" => parse_params::<DeployContractParams>(&params)
+            .map(|p| (default.clone(), p))
+            .and_then_async(|(b, p)| async move { b.deploy_contract(p).await })
+            .await,
+        "mint_tokens" => parse_params::<MintTokensParams>(&params)
+            .map(|p| (default.clone(), p))
+            .and_then_async(|(b, p)| async move { b.mint_tokens(p).await })
+            .await,
+        "transfer_tokens" => parse_params::<TransferTokensParams>(&params)
+            .map(|p| (default.clone(), p))
+            .and_then_async(|(b, p)| async move { b.transfer_tokens(p).await })
+            .await,
+        "transaction_history" => parse_params::<TransactionsQuery>(&params)
+            .and_then(|p| Ok((resolve(registry, p.chain_id, p.network.as_deref())?, p)))
+            .and_then_async(|(b, p)| async move { b.get_transactions(p.address, p.limit).await })
+            .await,
+        other => Err(RpcErrorBody { code: -32601, message: format!("Method not found: {}", other) }),
+    };
+
+    match result {
+        Ok(value) => RpcResponse::ok(id, value),
+        Err(e) => RpcResponse::err(id, e.code, e.message),
+    }
+}
+
+/// Resolves a request's `chain_id`/`network` against `registry`, mapping a
+/// lookup failure to the same RPC error shape as a bad params/call error.
+fn resolve(registry: &NetworkRegistry, chain_id: Option<u64>, network: Option<&str>) -> Result<Arc<BlockchainService>, RpcErrorBody> {
+    registry
+        .resolve(chain_id, network)
+        .map(Arc::clone)
+        .map_err(|e| RpcErrorBody { code: -32000, message: e.to_string() })
+}
+
+fn parse_params<T: for<'de> Deserialize<'de>>(params: &Value) -> Result<T, RpcErrorBody> {
+    serde_json::from_value(params.clone())
+        .map_err(|e| RpcErrorBody { code: -32602, message: format!("Invalid params: {}", e) })
+}
+
+/// Small helper trait so each dispatch arm reads as "parse params (plus
+/// whichever `BlockchainService` they resolved to), then call this async
+/// blockchain method", without repeating the call/serialize boilerplate
+/// five times over.
+trait AndThenAsync<T> {
+    async fn and_then_async<F, Fut, R>(self, f: F) -> Result<Value, RpcErrorBody>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: std::future::Future<Output = Result<R, crate::error::AppError>>,
+        R: Serialize;
+}
+
+impl<T> AndThenAsync<T> for Result<T, RpcErrorBody> {
+    async fn and_then_async<F, Fut, R>(self, f: F) -> Result<Value, RpcErrorBody>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: std::future::Future<Output = Result<R, crate::error::AppError>>,
+        R: Serialize,
+    {
+        let input = self?;
+        let result = f(input).await.map_err(|e| RpcErrorBody { code: -32000, message: e.to_string() })?;
+        serde_json::to_value(result).map_err(|e| RpcErrorBody { code: -32000, message: e.to_string() })
+    }
+}
@@ -175,22 +175,12 @@ pub mod contract_abis {
 }
 
 /// Gas price estimation strategies
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum GasStrategy {
     Slow,
+    #[default]
     Standard,
     Fast,
     Urgent,
 }
 
-impl GasStrategy {
-    /// Get gas price multiplier for the strategy
-    pub fn multiplier(self) -> f64 {
-        match self {
-            GasStrategy::Slow => 0.8,
-            GasStrategy::Standard => 1.0,
-            GasStrategy::Fast => 1.2,
-            GasStrategy::Urgent => 1.5,
-        }
-    }
-}
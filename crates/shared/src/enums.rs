@@ -12,6 +12,10 @@ pub enum ContractAction {
     Approve,
     MintNft,
     TransferNft,
+    /// ERC-1155 `balanceOfBatch`: balances for several token IDs at once.
+    BalanceOfBatch,
+    /// ERC-1155 `safeBatchTransferFrom`: transfer several token IDs at once.
+    TransferBatch,
 }
 
 impl Display for ContractAction {
@@ -23,6 +27,8 @@ impl Display for ContractAction {
             ContractAction::Approve => write!(f, "approve"),
             ContractAction::MintNft => write!(f, "mint-nft"),
             ContractAction::TransferNft => write!(f, "transfer-nft"),
+            ContractAction::BalanceOfBatch => write!(f, "balance-of-batch"),
+            ContractAction::TransferBatch => write!(f, "transfer-batch"),
         }
     }
 }
@@ -37,6 +43,8 @@ impl ContractAction {
             ContractAction::Approve,
             ContractAction::MintNft,
             ContractAction::TransferNft,
+            ContractAction::BalanceOfBatch,
+            ContractAction::TransferBatch,
         ]
     }
 
@@ -49,6 +57,8 @@ impl ContractAction {
             ContractAction::Approve => "Approve tokens for spending",
             ContractAction::MintNft => "Mint new NFT",
             ContractAction::TransferNft => "Transfer NFT to another address",
+            ContractAction::BalanceOfBatch => "Get balances for several ERC-1155 token ids at once",
+            ContractAction::TransferBatch => "Transfer several ERC-1155 token ids at once",
         }
     }
 
@@ -61,6 +71,8 @@ impl ContractAction {
             "approve" => Some(ContractAction::Approve),
             "mint-nft" => Some(ContractAction::MintNft),
             "transfer-nft" => Some(ContractAction::TransferNft),
+            "balance-of-batch" => Some(ContractAction::BalanceOfBatch),
+            "transfer-batch" => Some(ContractAction::TransferBatch),
             _ => None,
         }
     }
@@ -71,7 +83,14 @@ impl ContractAction {
 pub enum ContractTemplate {
     Erc20,
     Erc721,
+    /// Standard ERC-1155 multi-token contract (fungible and non-fungible
+    /// token ids in a single contract), e.g. `BalanceOfBatch`/`TransferBatch`
+    /// in [`ContractAction`].
+    Erc1155,
     Custom,
+    /// Rust smart contract compiled to `wasm32-unknown-unknown` and deployed
+    /// via `cargo stylus deploy` instead of the Solidity pipeline.
+    Stylus,
 }
 
 impl Display for ContractTemplate {
@@ -79,7 +98,9 @@ impl Display for ContractTemplate {
         match self {
             ContractTemplate::Erc20 => write!(f, "erc20"),
             ContractTemplate::Erc721 => write!(f, "erc721"),
+            ContractTemplate::Erc1155 => write!(f, "erc1155"),
             ContractTemplate::Custom => write!(f, "custom"),
+            ContractTemplate::Stylus => write!(f, "stylus"),
         }
     }
 }
@@ -87,7 +108,13 @@ impl Display for ContractTemplate {
 impl ContractTemplate {
     /// Get all possible contract templates
     pub fn all() -> &'static [ContractTemplate] {
-        &[ContractTemplate::Erc20, ContractTemplate::Erc721, ContractTemplate::Custom]
+        &[
+            ContractTemplate::Erc20,
+            ContractTemplate::Erc721,
+            ContractTemplate::Erc1155,
+            ContractTemplate::Custom,
+            ContractTemplate::Stylus,
+        ]
     }
 
     /// Get template description
@@ -95,7 +122,9 @@ impl ContractTemplate {
         match self {
             ContractTemplate::Erc20 => "Standard ERC20 token contract",
             ContractTemplate::Erc721 => "Standard ERC721 NFT contract",
+            ContractTemplate::Erc1155 => "Standard ERC1155 multi-token contract",
             ContractTemplate::Custom => "Custom contract template",
+            ContractTemplate::Stylus => "Rust smart contract (Arbitrum Stylus)",
         }
     }
 
@@ -104,16 +133,26 @@ impl ContractTemplate {
         match self {
             ContractTemplate::Erc20 => "MyToken",
             ContractTemplate::Erc721 => "MyNFT",
+            ContractTemplate::Erc1155 => "MyMultiToken",
             ContractTemplate::Custom => "CustomContract",
+            ContractTemplate::Stylus => "MyStylusContract",
         }
     }
 
+    /// True when this template is compiled to WASM and deployed via
+    /// `cargo stylus` instead of the Solidity toolchain.
+    pub fn is_stylus(&self) -> bool {
+        matches!(self, ContractTemplate::Stylus)
+    }
+
     /// Parse string to ContractTemplate
     pub fn parse(s: &str) -> Option<ContractTemplate> {
         match s.to_lowercase().as_str() {
             "erc20" => Some(ContractTemplate::Erc20),
             "erc721" => Some(ContractTemplate::Erc721),
+            "erc1155" => Some(ContractTemplate::Erc1155),
             "custom" => Some(ContractTemplate::Custom),
+            "stylus" => Some(ContractTemplate::Stylus),
             _ => None,
         }
     }
@@ -189,16 +228,6 @@ impl Display for GasPriceStrategy {
 }
 
 impl GasPriceStrategy {
-    /// Get gas price multiplier
-    pub fn multiplier(&self) -> f64 {
-        match self {
-            GasPriceStrategy::Slow => 0.8,
-            GasPriceStrategy::Standard => 1.0,
-            GasPriceStrategy::Fast => 1.2,
-            GasPriceStrategy::Urgent => 1.5,
-        }
-    }
-
     /// Parse string to GasPriceStrategy
     pub fn parse(s: &str) -> Option<GasPriceStrategy> {
         match s.to_lowercase().as_str() {
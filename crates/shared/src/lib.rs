@@ -5,6 +5,7 @@
 pub mod common_types;
 pub mod constants;
 pub mod enums;
+pub mod serde_u256;
 pub mod types;
 pub mod utils;
 
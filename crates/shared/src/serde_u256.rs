@@ -0,0 +1,42 @@
+//! Decimal/hex-string (de)serialization for `U256`, mirroring
+//! `server::models::u256_ser`/`u256_ser_option`, so typed-transaction fee
+//! fields stay human-readable on the wire instead of relying on alloy's own
+//! `Serialize` impl.
+
+/// `#[serde(with = "u256_ser")]` for a required `U256` field.
+pub mod u256_ser {
+    use alloy::primitives::U256;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        U256::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// `#[serde(with = "u256_ser_option")]` for an `Option<U256>` field.
+pub mod u256_ser_option {
+    use alloy::primitives::U256;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(value: &Option<U256>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => super::u256_ser::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<U256>, D::Error> {
+        let opt = Option::<String>::deserialize(deserializer)?;
+        match opt {
+            Some(s) => Ok(Some(U256::from_str(&s).map_err(serde::de::Error::custom)?)),
+            None => Ok(None),
+        }
+    }
+}
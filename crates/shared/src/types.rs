@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::serde_u256::u256_ser_option;
+use alloy::primitives::aliases::B256;
 
 // Re-export commonly used alloy types
 pub use alloy::primitives::{Address, TxHash as H256, U256};
@@ -34,6 +36,25 @@ pub struct Transaction {
     pub block_hash: H256,
     pub transaction_index: u64,
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// EIP-2718 transaction type: `0` legacy, `1` EIP-2930, `2` EIP-1559.
+    #[serde(default)]
+    pub tx_type: u8,
+    /// EIP-1559 (type 2): the max total fee per gas the sender will pay.
+    #[serde(default, with = "u256_ser_option")]
+    pub max_fee_per_gas: Option<U256>,
+    /// EIP-1559 (type 2): the max priority fee (tip) per gas the sender will pay.
+    #[serde(default, with = "u256_ser_option")]
+    pub max_priority_fee_per_gas: Option<U256>,
+    /// EIP-2930 (type 1+): addresses/storage slots the transaction pre-declares access to.
+    #[serde(default)]
+    pub access_list: Option<Vec<AccessListItem>>,
+}
+
+/// A single entry of an EIP-2930 access list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<B256>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,28 +53,72 @@ pub fn parse_amount(amount_str: &str) -> Result<U256, AppError> {
         .map_err(|_| AppError::ValidationError(format!("{}: {}", errors::INVALID_AMOUNT_PREFIX, amount_str)))
 }
 
-/// Convert wei to ether string representation
+fn pow10(decimals: u8) -> U256 {
+    let mut result = U256::from(1u64);
+    for _ in 0..decimals {
+        result *= U256::from(10u64);
+    }
+    result
+}
+
+/// Renders `value / 10^decimals` as a decimal string, with trailing
+/// fractional zeros stripped (e.g. `1500000000000000000` at 18 decimals
+/// becomes `"1.5"`, not `"1.500000000000000000"`). Pure `U256` integer
+/// division, so it never loses precision the way a `f64` round-trip would.
+pub fn format_units(value: U256, decimals: u8) -> String {
+    let divisor = pow10(decimals);
+    let int_part = value / divisor;
+    let frac_part = value % divisor;
+
+    if frac_part.is_zero() {
+        return int_part.to_string();
+    }
+
+    let frac_str = format!("{:0>width$}", frac_part.to_string(), width = decimals as usize);
+    let frac_str = frac_str.trim_end_matches('0');
+
+    format!("{}.{}", int_part, frac_str)
+}
+
+/// Parses a decimal string into its `value * 10^decimals` integer form,
+/// the inverse of [`format_units`]. Rejects more than one `.` and more
+/// fractional digits than `decimals` allows.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<U256, AppError> {
+    let invalid = || AppError::ValidationError(format!("{}: {}", errors::INVALID_AMOUNT_PREFIX, amount));
+
+    if amount.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut parts = amount.split('.');
+    let int_str = parts.next().ok_or_else(invalid)?;
+    let frac_str = parts.next().unwrap_or("");
+    if parts.next().is_some() || frac_str.len() > decimals as usize {
+        return Err(invalid());
+    }
+    if int_str.is_empty() && frac_str.is_empty() {
+        // Bare "." (or "" -- already rejected above) splits into two empty
+        // strings, which would otherwise sail through as 0.
+        return Err(invalid());
+    }
+
+    let int_part = if int_str.is_empty() { U256::ZERO } else { int_str.parse::<U256>().map_err(|_| invalid())? };
+    let frac_padded = format!("{:0<width$}", frac_str, width = decimals as usize);
+    let frac_part = if frac_padded.is_empty() { U256::ZERO } else { frac_padded.parse::<U256>().map_err(|_| invalid())? };
+
+    let divisor = pow10(decimals);
+    Ok(int_part * divisor + frac_part)
+}
+
+/// Convert wei to ether string representation (thin 18-decimal wrapper over
+/// [`format_units`]; see that function for the exact-integer math).
 pub fn wei_to_ether(wei: U256) -> String {
-    let ether_value = wei.to::<u128>() as f64 / 1e18;
-    format!("{:.6}", ether_value)
+    format_units(wei, 18)
 }
 
-/// Convert ether string to wei
+/// Convert ether string to wei (thin 18-decimal wrapper over [`parse_units`]).
 pub fn ether_to_wei(ether_str: &str) -> Result<U256, AppError> {
-    let ether_value: f64 = ether_str
-        .parse()
-        .map_err(|_| AppError::ValidationError(format!("{}: {}", errors::INVALID_AMOUNT_PREFIX, ether_str)))?;
-    
-    let wei_value = (ether_value * 1e18) as u128;
-    Ok(U256::from(wei_value))
-}
-
-/// Calculate gas price based on strategy
-pub fn calculate_gas_price(base_gas_price: U256, strategy: GasStrategy) -> U256 {
-    let multiplier = strategy.multiplier();
-    let base_value = base_gas_price.to::<u128>() as f64;
-    let adjusted_value = base_value * multiplier;
-    U256::from(adjusted_value as u128)
+    parse_units(ether_str, 18)
 }
 
 /// Create a timeout duration for network operations
@@ -0,0 +1,29 @@
+//! Scans `abis/` for Hardhat/Solidity artifact JSON files and emits
+//! `sol!`-backed Rust bindings into `OUT_DIR`, included from `src/lib.rs`
+//! via `include!(concat!(env!("OUT_DIR"), "/contract_bindings.rs"))`.
+//!
+//! Shares its scanning/codegen logic with `src/codegen.rs` (which exposes
+//! the same functions for runtime introspection) through
+//! `include!("codegen_shared.rs")` rather than duplicating it.
+
+use std::env;
+use std::path::PathBuf;
+
+include!("codegen_shared.rs");
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo"));
+    let abis_dir = env::var("CONTRACT_ABIS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| manifest_dir.join("abis"));
+
+    println!("cargo:rerun-if-changed={}", abis_dir.display());
+    println!("cargo:rerun-if-env-changed=CONTRACT_ABIS_DIR");
+
+    let artifacts = scan_abi_directory(&abis_dir);
+    let source = generate_bindings_source(&artifacts);
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    std::fs::write(out_dir.join("contract_bindings.rs"), source)
+        .expect("failed to write generated contract bindings");
+}
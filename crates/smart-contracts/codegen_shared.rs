@@ -0,0 +1,166 @@
+//! Shared between `build.rs` and `src/codegen.rs` via `include!`, so the
+//! ABI-scanning/binding-generation logic isn't duplicated between the build
+//! script and the runtime-facing module that exposes the same functions for
+//! introspection (e.g. a future CLI command listing what `build.rs` would
+//! generate without re-running it).
+
+/// One `.json` ABI/artifact file discovered under the configured `abis/`
+/// directory, with its contract name resolved from the file stem and its
+/// ABI entries already unwrapped from Hardhat's `{ "abi": [...] }` artifact
+/// shape (a bare `[...]` ABI array is accepted too).
+#[derive(Debug, Clone)]
+pub struct AbiArtifact {
+    pub contract_name: String,
+    pub abi: Vec<serde_json::Value>,
+}
+
+/// Optional function/event allowlist for one contract, read from a sibling
+/// `<name>.select.json` file next to `<name>.json`. Keeps large ABIs from
+/// emitting (and compiling) bindings for entries nothing actually calls.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct BindingSelection {
+    #[serde(default)]
+    pub functions: Vec<String>,
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+/// Scans `dir` for `*.json` artifact files (skipping `*.select.json`
+/// selection files), parsing each into an [`AbiArtifact`] and applying its
+/// sibling [`BindingSelection`] if one exists. Returns an empty list (not
+/// an error) when `dir` doesn't exist, so a project with no `abis/`
+/// directory yet still builds.
+pub fn scan_abi_directory(dir: &std::path::Path) -> Vec<AbiArtifact> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut artifacts = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let file_name = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+        if !file_name.ends_with(".json") || file_name.ends_with(".select.json") {
+            continue;
+        }
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(_) => continue,
+        };
+        let raw: serde_json::Value = match serde_json::from_str(&contents) {
+            Ok(raw) => raw,
+            Err(_) => continue,
+        };
+
+        let abi = match raw {
+            serde_json::Value::Array(entries) => entries,
+            serde_json::Value::Object(ref obj) => match obj.get("abi") {
+                Some(serde_json::Value::Array(entries)) => entries.clone(),
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let contract_name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("Contract").to_string();
+        let selection = read_selection(&path);
+        let abi = apply_selection(abi, selection.as_ref());
+
+        artifacts.push(AbiArtifact { contract_name, abi });
+    }
+
+    artifacts.sort_by(|a, b| a.contract_name.cmp(&b.contract_name));
+    artifacts
+}
+
+fn read_selection(artifact_path: &std::path::Path) -> Option<BindingSelection> {
+    let select_path = artifact_path.with_extension("select.json");
+    let contents = std::fs::read_to_string(select_path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Keeps only the ABI entries named in `selection` (functions and events,
+/// matched by their `"name"` field); constructors, fallbacks, and receive
+/// entries always pass through untouched since callers never select those
+/// by name. With no selection file, the full ABI passes through unchanged.
+fn apply_selection(abi: Vec<serde_json::Value>, selection: Option<&BindingSelection>) -> Vec<serde_json::Value> {
+    let selection = match selection {
+        Some(selection) => selection,
+        None => return abi,
+    };
+
+    abi.into_iter()
+        .filter(|entry| {
+            let entry_type = entry.get("type").and_then(|t| t.as_str()).unwrap_or("");
+            match entry_type {
+                "function" => entry
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .map_or(false, |name| selection.functions.iter().any(|f| f == name)),
+                "event" => entry
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .map_or(false, |name| selection.events.iter().any(|e| e == name)),
+                _ => true,
+            }
+        })
+        .collect()
+}
+
+/// Valid Rust module identifier for `contract_name`: lowercased, with
+/// non-alphanumeric runs collapsed to a single `_`.
+pub fn module_name(contract_name: &str) -> String {
+    let mut out = String::new();
+    let mut last_was_sep = true;
+    for c in contract_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+            last_was_sep = false;
+        } else if !last_was_sep {
+            out.push('_');
+            last_was_sep = true;
+        }
+    }
+    out.trim_matches('_').to_string()
+}
+
+/// `UpperCamelCase` `sol!` type name matching [`module_name`]'s module.
+pub fn type_name(contract_name: &str) -> String {
+    module_name(contract_name)
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Emits one `pub mod <module_name> { alloy::sol!(...) }` block per
+/// artifact, declaring its (possibly trimmed) ABI inline as the `sol!`
+/// macro's JSON input. A pure string transform so it's callable from both
+/// `build.rs` (writing to `OUT_DIR`) and `src/codegen.rs` (introspection).
+pub fn generate_bindings_source(artifacts: &[AbiArtifact]) -> String {
+    let mut out = String::new();
+    out.push_str("// @generated by smart-contracts/build.rs from abis/*.json -- do not edit by hand.\n\n");
+
+    for artifact in artifacts {
+        let module = module_name(&artifact.contract_name);
+        let ty = type_name(&artifact.contract_name);
+        let abi_json = serde_json::to_string(&artifact.abi).unwrap_or_else(|_| "[]".to_string());
+
+        out.push_str(&format!(
+            "pub mod {module} {{\n    alloy::sol!(\n        #[allow(missing_docs)]\n        {ty},\n        r#\"{abi}\"#\n    );\n}}\n\n",
+            module = module,
+            ty = ty,
+            abi = abi_json,
+        ));
+    }
+
+    out
+}
@@ -0,0 +1,7 @@
+//! Runtime-facing wrapper over the ABI-scanning/codegen logic `build.rs`
+//! uses to populate `OUT_DIR`, shared via `include!` so both copies stay in
+//! sync. Lets a caller introspect which contracts/functions/events `abis/`
+//! would generate bindings for (e.g. to list them in a CLI command) without
+//! re-running the build script.
+
+include!(concat!(env!("CARGO_MANIFEST_DIR"), "/codegen_shared.rs"));
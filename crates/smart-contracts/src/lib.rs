@@ -7,10 +7,87 @@
 //! - Type-safe contract interfaces
 
 use alloy::sol;
-use alloy::primitives::Address;
+use alloy::primitives::{Address, TxHash};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ethereum_boilerplate_shared::SUPPORTED_NETWORKS;
 
+pub mod codegen;
+pub mod middleware;
+pub mod plan;
+
+/// Contract bindings generated at build time from `abis/*.json` by
+/// `build.rs` -- one `pub mod <contract_name>` per artifact, each holding
+/// the `sol!`-backed call/event types for that ABI (trimmed to whatever a
+/// sibling `<name>.select.json` selects, or the full ABI otherwise). Empty
+/// when the project has no `abis/` directory. See `codegen` for the shared
+/// scanning logic.
+pub mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/contract_bindings.rs"));
+}
+
+/// Default path for the persisted multi-network deployment registry.
+pub const DEFAULT_REGISTRY_PATH: &str = "contracts.registry.json";
+
+/// Name of the result file a `cargo run --bin deploy` / `cargo stylus
+/// deploy` subprocess would write, in its working directory, once it had
+/// actually broadcast a deployment transaction. The CLI has no other way to
+/// learn the real deployed address -- it must not guess one.
+///
+/// IMPORTANT: nothing in this tree writes this file yet. There is no
+/// `src/bin/deploy.rs` in this crate and no `contracts/<name>` Stylus crate
+/// for `cargo stylus deploy` to run against, so [`DeploySubprocessResult::read`]
+/// will never find one to read -- the CLI's conditional registry-recording
+/// path is effectively dormant until one of those subprocesses exists and is
+/// taught to emit this file. Don't read the conditional recording logic as
+/// evidence that deployments are being captured automatically; they aren't,
+/// on this tree, for any contract.
+pub const DEPLOY_RESULT_FILE: &str = "deploy-result.json";
+
+/// What a deploy subprocess would report back once it had actually
+/// broadcast a transaction: the real deployed address plus whatever
+/// provenance it has at that point. Read via [`DeploySubprocessResult::read`]
+/// from [`DEPLOY_RESULT_FILE`] in the subprocess's working directory -- see
+/// that constant's docs for why this currently never finds one.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploySubprocessResult {
+    pub address: Address,
+    pub transaction_hash: Option<TxHash>,
+    pub block_number: Option<u64>,
+}
+
+impl DeploySubprocessResult {
+    /// Reads [`DEPLOY_RESULT_FILE`] from `dir`, if the subprocess run there
+    /// wrote one. Returns `None` (rather than a fabricated address) when
+    /// the file is missing or doesn't parse -- callers must treat that as
+    /// "the real address isn't known yet".
+    pub fn read(dir: &str) -> Option<Self> {
+        let path = std::path::Path::new(dir).join(DEPLOY_RESULT_FILE);
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+}
+
+/// One recorded deployment: address plus the provenance the server/frontend
+/// need to resolve and verify it (tx hash, block, ABI path, deployer).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentRecord {
+    pub address: Address,
+    pub transaction_hash: Option<TxHash>,
+    pub block_number: Option<u64>,
+    pub abi_path: Option<String>,
+    pub deployer: Option<Address>,
+}
+
+/// Key identifying a deployment slot: the same contract name on different
+/// chains gets its own entry instead of overwriting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct RegistryKey {
+    network_name: String,
+    chain_id: u64,
+    contract_name: String,
+}
+
 // Generate typed contracts using Alloy's sol! macro
 sol! {
     interface IERC20 {
@@ -26,41 +103,111 @@ sol! {
     }
 }
 
-/// Contract manager for tracking deployed contracts
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegistryEntry {
+    key: RegistryKey,
+    record: DeploymentRecord,
+}
+
+/// Contract manager for tracking deployed contracts, persisted to a JSON
+/// registry keyed by `(network_name, chain_id, contract_name)` so the same
+/// contract name on different chains never overwrites another deployment.
+/// `ContractManager::load`/`save` read and write that file; the CLI's
+/// `deploy` command records and looks up deployments through it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContractManager {
-    contracts: HashMap<String, Address>,
+    entries: Vec<RegistryEntry>,
 }
 
 impl ContractManager {
-    /// Create new contract manager
+    /// Create new, empty contract manager (not yet persisted anywhere).
     pub fn new() -> Self {
-        Self {
-            contracts: HashMap::new(),
+        Self { entries: Vec::new() }
+    }
+
+    /// Loads the registry from `path`, or returns an empty manager if the
+    /// file doesn't exist yet.
+    pub fn load(path: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persists the registry to `path` as pretty-printed JSON.
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .expect("ContractManager serialization is infallible");
+        std::fs::write(path, content)
+    }
+
+    /// Records (or replaces) a deployment for `contract_name` on the given
+    /// network/chain. Recording the same name under a different chain id
+    /// creates a new entry rather than overwriting the existing one.
+    pub fn record_deployment(
+        &mut self,
+        network_name: &str,
+        chain_id: u64,
+        contract_name: &str,
+        record: DeploymentRecord,
+    ) {
+        let key = RegistryKey {
+            network_name: network_name.to_string(),
+            chain_id,
+            contract_name: contract_name.to_string(),
+        };
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.record = record;
+        } else {
+            self.entries.push(RegistryEntry { key, record });
         }
     }
 
-    /// Add contract address manually
-    pub fn add_contract(&mut self, name: String, address: Address) {
-        self.contracts.insert(name, address);
+    /// Add contract address manually under the given network/chain, with no
+    /// further provenance (a thin convenience over `record_deployment`).
+    pub fn add_contract(&mut self, network_name: &str, chain_id: u64, name: String, address: Address) {
+        self.record_deployment(
+            network_name,
+            chain_id,
+            &name,
+            DeploymentRecord { address, transaction_hash: None, block_number: None, abi_path: None, deployer: None },
+        );
     }
 
-    /// Get deployed contract address by name
-    pub fn get_contract(&self, name: &str) -> Option<Address> {
-        self.contracts.get(name).copied()
+    /// Get a deployed contract's address by name on a specific network/chain.
+    pub fn get_contract(&self, network_name: &str, chain_id: u64, name: &str) -> Option<Address> {
+        self.entries
+            .iter()
+            .find(|e| e.key.network_name == network_name && e.key.chain_id == chain_id && e.key.contract_name == name)
+            .map(|e| e.record.address)
     }
 
-    /// List all deployed contracts
-    pub fn list_contracts(&self) -> Vec<(String, Address)> {
-        self.contracts
+    /// Look up a deployment by chain id alone (useful when the caller only
+    /// knows the chain, not its configured network name).
+    pub fn get_by_chain_id(&self, chain_id: u64, name: &str) -> Option<&DeploymentRecord> {
+        self.entries
             .iter()
-            .map(|(name, addr)| (name.clone(), *addr))
+            .find(|e| e.key.chain_id == chain_id && e.key.contract_name == name)
+            .map(|e| &e.record)
+    }
+
+    /// List all deployed contracts, optionally filtered to one network.
+    pub fn list_contracts(&self, network_name: Option<&str>) -> Vec<(String, u64, Address)> {
+        self.entries
+            .iter()
+            .filter(|e| network_name.map_or(true, |n| e.key.network_name == n))
+            .map(|e| (e.key.contract_name.clone(), e.key.chain_id, e.record.address))
             .collect()
     }
 
-    /// Remove contract from tracking
-    pub fn remove_contract(&mut self, name: &str) -> Option<Address> {
-        self.contracts.remove(name)
+    /// Remove a contract from tracking on a specific network/chain.
+    pub fn remove_contract(&mut self, network_name: &str, chain_id: u64, name: &str) -> Option<Address> {
+        let idx = self
+            .entries
+            .iter()
+            .position(|e| e.key.network_name == network_name && e.key.chain_id == chain_id && e.key.contract_name == name)?;
+        Some(self.entries.remove(idx).record.address)
     }
 }
 
@@ -0,0 +1,173 @@
+//! Composable provider middleware for contract deployments
+//!
+//! Mirrors the stackable provider-middleware pattern: a deploy request flows
+//! signer -> `NonceManager` -> `GasOracle` -> provider, with each layer only
+//! touching the fields it's responsible for before delegating downward.
+
+use alloy::primitives::U256;
+use alloy::providers::{Provider, ReqwestProvider};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Number of recent blocks sampled by `eth_feeHistory` for
+/// [`PriorityFeeStrategy::Percentile`].
+const FEE_HISTORY_BLOCK_COUNT: u64 = 10;
+/// Priority fee used when `eth_feeHistory` returns no reward samples (e.g.
+/// an idle chain with no recent paid priority fees).
+const MIN_PRIORITY_FEE_WEI: u64 = 1_000_000_000;
+
+/// Priority-fee strategy used by [`GasOracle`] to derive `max_priority_fee_per_gas`.
+#[derive(Debug, Clone)]
+pub enum PriorityFeeStrategy {
+    /// A fixed priority fee, in Wei.
+    Fixed(U256),
+    /// A percentile (0-100) of recent block rewards, fetched via `eth_feeHistory`.
+    Percentile(f64),
+}
+
+impl Default for PriorityFeeStrategy {
+    fn default() -> Self {
+        // 1.5 Gwei is a reasonable default priority fee absent better signal.
+        PriorityFeeStrategy::Fixed(U256::from(1_500_000_000u64))
+    }
+}
+
+/// Suggested EIP-1559 fee values for a pending deploy/call.
+#[derive(Debug, Clone, Copy)]
+pub struct GasEstimate {
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Caches the account nonce locally and hands out `nonce + 1` for each queued
+/// transaction, so a batch of deploys in one run doesn't collide on the same
+/// on-chain nonce. Call [`NonceManager::reset`] after an RPC rejection so the
+/// next transaction re-fetches from the node instead of trusting the cache.
+pub struct NonceManager {
+    provider: Arc<ReqwestProvider>,
+    cached: AtomicU64,
+    primed: std::sync::atomic::AtomicBool,
+}
+
+impl NonceManager {
+    pub fn new(provider: Arc<ReqwestProvider>) -> Self {
+        Self {
+            provider,
+            cached: AtomicU64::new(0),
+            primed: std::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the next nonce to use, priming the cache from
+    /// `eth_getTransactionCount` on first use.
+    pub async fn next_nonce(&self, address: alloy::primitives::Address) -> Result<u64, String> {
+        if !self.primed.swap(true, Ordering::SeqCst) {
+            let onchain = self
+                .provider
+                .get_transaction_count(address)
+                .await
+                .map_err(|e| e.to_string())?;
+            self.cached.store(onchain, Ordering::SeqCst);
+            return Ok(onchain);
+        }
+
+        Ok(self.cached.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Drops the local cache, forcing the next call to re-fetch from the node.
+    /// Use this after a nonce-related RPC rejection (e.g. "nonce too low").
+    pub fn reset(&self) {
+        self.primed.store(false, Ordering::SeqCst);
+        self.cached.store(0, Ordering::SeqCst);
+    }
+}
+
+/// Estimates EIP-1559 fees from the latest base fee plus a configurable
+/// priority-fee strategy.
+pub struct GasOracle {
+    provider: Arc<ReqwestProvider>,
+    strategy: PriorityFeeStrategy,
+}
+
+impl GasOracle {
+    pub fn new(provider: Arc<ReqwestProvider>, strategy: PriorityFeeStrategy) -> Self {
+        Self { provider, strategy }
+    }
+
+    /// Estimates `max_fee_per_gas`/`max_priority_fee_per_gas` for the next block.
+    pub async fn estimate(&self) -> Result<GasEstimate, String> {
+        let latest = self
+            .provider
+            .get_block_by_number(alloy::eips::BlockNumberOrTag::Latest, false)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "no latest block returned".to_string())?;
+
+        let base_fee = latest
+            .header
+            .base_fee_per_gas
+            .map(U256::from)
+            .unwrap_or_else(|| U256::from(1_000_000_000u64));
+
+        let priority_fee = self.priority_fee().await?;
+
+        Ok(GasEstimate {
+            // Headroom for up to five consecutive max (12.5%) base-fee increases.
+            max_fee_per_gas: base_fee * U256::from(2u64) + priority_fee,
+            max_priority_fee_per_gas: priority_fee,
+        })
+    }
+
+    async fn priority_fee(&self) -> Result<U256, String> {
+        match &self.strategy {
+            PriorityFeeStrategy::Fixed(fee) => Ok(*fee),
+            PriorityFeeStrategy::Percentile(pct) => {
+                let history = self
+                    .provider
+                    .get_fee_history(FEE_HISTORY_BLOCK_COUNT, alloy::eips::BlockNumberOrTag::Latest, &[*pct])
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                let mut rewards: Vec<u128> = history
+                    .reward
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|block_rewards| block_rewards.first().copied())
+                    .collect();
+
+                if rewards.is_empty() {
+                    return Ok(U256::from(MIN_PRIORITY_FEE_WEI));
+                }
+
+                rewards.sort_unstable();
+                Ok(U256::from(rewards[rewards.len() / 2]))
+            }
+        }
+    }
+}
+
+/// Chains [`NonceManager`] and [`GasOracle`] so a single call yields both the
+/// nonce and fee fields a deployment transaction needs.
+pub struct DeployMiddlewareStack {
+    pub nonce_manager: NonceManager,
+    pub gas_oracle: GasOracle,
+}
+
+impl DeployMiddlewareStack {
+    pub fn new(provider: Arc<ReqwestProvider>, strategy: PriorityFeeStrategy) -> Self {
+        Self {
+            nonce_manager: NonceManager::new(provider.clone()),
+            gas_oracle: GasOracle::new(provider, strategy),
+        }
+    }
+
+    /// Prepares the nonce and gas fields for the next deployment in a batch.
+    pub async fn prepare(
+        &self,
+        deployer: alloy::primitives::Address,
+    ) -> Result<(u64, GasEstimate), String> {
+        let nonce = self.nonce_manager.next_nonce(deployer).await?;
+        let gas = self.gas_oracle.estimate().await?;
+        Ok((nonce, gas))
+    }
+}
@@ -0,0 +1,96 @@
+//! Deploy plans: ordered, resumable multi-contract deployments with a
+//! simulate-then-broadcast workflow, mirroring the deploy-script pattern of
+//! mature tooling (forge-script style "plan files").
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A single contract entry in a deploy plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedContract {
+    pub name: String,
+    pub template: String,
+    #[serde(default)]
+    pub constructor_args: Vec<String>,
+    /// Function calls to make against the contract right after it deploys,
+    /// e.g. `["setOwner(address)"]`.
+    #[serde(default)]
+    pub post_deploy_calls: Vec<String>,
+}
+
+/// An ordered list of contracts plus constructor args and post-deploy calls,
+/// executed as a simulate phase (`--dry-run`) followed by an optional
+/// broadcast phase (`--broadcast`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployPlan {
+    pub network: String,
+    pub contracts: Vec<PlannedContract>,
+}
+
+impl DeployPlan {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    }
+}
+
+/// Result of simulating a single planned contract's deployment.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedDeploy {
+    pub name: String,
+    pub predicted_address: alloy::primitives::Address,
+    pub estimated_gas: u64,
+}
+
+/// A recorded, already-broadcast deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployedContract {
+    pub address: alloy::primitives::Address,
+    pub transaction_hash: Option<alloy::primitives::TxHash>,
+    pub block_number: Option<u64>,
+}
+
+/// The on-disk address book: network name -> contract name -> deployment.
+///
+/// Deployments are keyed per network so reruns of the same plan against a
+/// different chain never clobber an existing entry, and so a plan can be
+/// resumed, skipping contracts that already have a recorded address.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddressBook {
+    networks: HashMap<String, HashMap<String, DeployedContract>>,
+}
+
+impl AddressBook {
+    pub fn load(path: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, content).map_err(|e| e.to_string())
+    }
+
+    pub fn get(&self, network: &str, contract: &str) -> Option<&DeployedContract> {
+        self.networks.get(network)?.get(contract)
+    }
+
+    pub fn record(&mut self, network: &str, contract: &str, deployed: DeployedContract) {
+        self.networks
+            .entry(network.to_string())
+            .or_default()
+            .insert(contract.to_string(), deployed);
+    }
+
+    /// True if the contract already has a recorded deployment on this
+    /// network, so `DeployPlan` execution can skip and resume.
+    pub fn already_deployed(&self, network: &str, contract: &str) -> bool {
+        self.get(network, contract).is_some()
+    }
+}
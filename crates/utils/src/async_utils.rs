@@ -1,8 +1,13 @@
 //! Async utilities and patterns for Web3 infrastructure
 
+use alloy::primitives::{Address, B256};
+use alloy::providers::{Provider, ReqwestProvider};
+use alloy::rpc::types::eth::TransactionReceipt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::mpsc;
 use tokio::time::sleep;
 use crate::error::{Result, UtilsError};
 
@@ -182,4 +187,191 @@ impl BatchProcessor {
 
         Ok(results)
     }
+}
+
+// --- Pending transaction watcher ---
+
+/// How often [`PendingTransaction`] polls `eth_getTransactionReceipt`/
+/// `eth_blockNumber` between attempts, paced through a [`Throttler`] so a
+/// short `with_timeout` deadline can't turn into a busy loop.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(2_000);
+
+/// Live status of a transaction being watched by [`PendingTransaction`],
+/// emitted on the stream-style [`PendingTransaction::watch`] channel.
+#[derive(Debug, Clone)]
+pub enum TxStatus {
+    /// No receipt yet; still sitting in the mempool (or simply slow to
+    /// propagate).
+    Pending,
+    /// A receipt was found but it hasn't cleared `confirmations` blocks yet.
+    Mined {
+        receipt: TransactionReceipt,
+        confirmations: u64,
+    },
+    /// The receipt cleared the required number of confirmations.
+    Confirmed { receipt: TransactionReceipt },
+}
+
+/// Watches a single transaction hash until it's mined and buried under the
+/// requested number of confirmations, the way ethers.js's
+/// `TransactionResponse.wait()` does. Polls `eth_getTransactionReceipt` on
+/// [`DEFAULT_POLL_INTERVAL`] (or [`Self::with_poll_interval`]) through a
+/// [`Throttler`], and once a receipt appears, polls `eth_blockNumber` the
+/// same way until `receipt.block_number + confirmations <= latest`.
+pub struct PendingTransaction {
+    provider: Arc<ReqwestProvider>,
+    hash: B256,
+    poll_interval: Duration,
+}
+
+impl PendingTransaction {
+    pub fn new(provider: Arc<ReqwestProvider>, hash: B256) -> Self {
+        Self {
+            provider,
+            hash,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Waits for the receipt to appear and accrue `confirmations` blocks,
+    /// or fails with a `UtilsError::Timeout` once `timeout` elapses.
+    pub async fn await_receipt(&self, confirmations: u64, timeout: Duration) -> Result<TransactionReceipt> {
+        with_timeout(self.poll_until_confirmed(confirmations), timeout).await?
+    }
+
+    /// Stream-style equivalent of [`Self::await_receipt`]: spawns a
+    /// background task that sends a [`TxStatus`] update on every state
+    /// change (mirrors [`crate::subscription::LogSubscriber::subscribe_logs`]'s
+    /// channel-per-subscription shape), so a caller like the Axum
+    /// transactions route can report live status instead of blocking on a
+    /// single future. The channel closes once the transaction is confirmed,
+    /// dropped-and-replaced, or the receiving end is dropped.
+    pub fn watch(self, confirmations: u64) -> mpsc::UnboundedReceiver<Result<TxStatus>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            if tx.send(Ok(TxStatus::Pending)).is_err() {
+                return;
+            }
+
+            let mut throttler = Throttler::new(self.poll_interval);
+
+            loop {
+                match throttler.throttle(self.poll_once(confirmations)).await {
+                    Ok(status @ TxStatus::Confirmed { .. }) => {
+                        let _ = tx.send(Ok(status));
+                        return;
+                    }
+                    Ok(status) => {
+                        if tx.send(Ok(status)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e));
+                        return;
+                    }
+                }
+            }
+        });
+
+        rx
+    }
+
+    /// Polls until a receipt appears and is confirmed, pacing attempts
+    /// through a [`Throttler`] set to `poll_interval`.
+    async fn poll_until_confirmed(&self, confirmations: u64) -> Result<TransactionReceipt> {
+        let mut throttler = Throttler::new(self.poll_interval);
+
+        loop {
+            if let TxStatus::Confirmed { receipt } = throttler.throttle(self.poll_once(confirmations)).await? {
+                return Ok(receipt);
+            }
+        }
+    }
+
+    /// Single poll iteration: checks for a receipt, then (once one exists)
+    /// how many confirmations it has.
+    async fn poll_once(&self, confirmations: u64) -> Result<TxStatus> {
+        let receipt = self
+            .provider
+            .get_transaction_receipt(self.hash)
+            .await
+            .map_err(|e| UtilsError::Ethereum(e.to_string()))?;
+
+        let receipt = match receipt {
+            Some(receipt) => receipt,
+            None => {
+                self.check_not_dropped().await?;
+                return Ok(TxStatus::Pending);
+            }
+        };
+
+        let mined_at = receipt.block_number.unwrap_or(0);
+        let latest = self
+            .provider
+            .get_block_number()
+            .await
+            .map_err(|e| UtilsError::Ethereum(e.to_string()))?;
+
+        let confirmed_blocks = latest.saturating_sub(mined_at);
+        if confirmed_blocks >= confirmations {
+            Ok(TxStatus::Confirmed { receipt })
+        } else {
+            Ok(TxStatus::Mined {
+                receipt,
+                confirmations: confirmed_blocks,
+            })
+        }
+    }
+
+    /// Detects a dropped-and-replaced transaction: the receipt never shows
+    /// up, but the sender's on-chain nonce has already moved past the one
+    /// this transaction was signed with, meaning some other hash consumed
+    /// it (a fee bump or a manual cancellation).
+    async fn check_not_dropped(&self) -> Result<()> {
+        let pending_tx = self
+            .provider
+            .get_transaction_by_hash(self.hash)
+            .await
+            .map_err(|e| UtilsError::Ethereum(e.to_string()))?;
+
+        let Some(pending_tx) = pending_tx else {
+            return Ok(());
+        };
+
+        let sender: Address = pending_tx.from;
+        let current_nonce = self
+            .provider
+            .get_transaction_count(sender)
+            .await
+            .map_err(|e| UtilsError::Ethereum(e.to_string()))?;
+
+        if current_nonce > pending_tx.nonce {
+            return Err(UtilsError::transaction_error(format!(
+                "transaction {} was dropped and replaced: sender {} has since used nonce {} (tx was signed with {})",
+                self.hash, sender, current_nonce.saturating_sub(1), pending_tx.nonce
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// One-shot convenience wrapper around [`PendingTransaction::await_receipt`]
+/// for callers that don't need the stream-style API.
+pub async fn await_receipt(
+    provider: Arc<ReqwestProvider>,
+    hash: B256,
+    confirmations: u64,
+    timeout: Duration,
+) -> Result<TransactionReceipt> {
+    PendingTransaction::new(provider, hash)
+        .await_receipt(confirmations, timeout)
+        .await
 }
\ No newline at end of file
@@ -11,6 +11,60 @@ pub struct Config {
     pub log_level: String,
     pub server_port: u16,
     pub network: NetworkConfig,
+    /// Optional endpoint that `bench` results (git commit, timestamp,
+    /// per-command stats) are POSTed to after a run, so CI can track
+    /// regressions over time.
+    #[serde(default)]
+    pub bench_results_url: Option<String>,
+    /// S3-compatible bucket release artifacts are uploaded to when `frontend
+    /// --build --upload` is used.
+    #[serde(default)]
+    pub release: Option<ReleaseConfig>,
+    /// Image tag and base-image overrides for `frontend --build --docker`.
+    #[serde(default)]
+    pub docker: Option<DockerConfig>,
+    /// Overrides `RetryConfig`'s default retry count for `RetryClient`
+    /// (network/wallet RPC reads); unset uses `network::MAX_RETRIES`.
+    #[serde(default)]
+    pub max_rpc_retries: Option<u32>,
+}
+
+/// Credentials and bucket location for the release artifact uploader.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseConfig {
+    /// Base URL of the S3-compatible endpoint, e.g. `https://s3.us-east-1.amazonaws.com`.
+    pub s3_endpoint: String,
+    pub s3_bucket: String,
+    pub s3_region: String,
+    pub s3_access_key: String,
+    pub s3_secret_key: String,
+}
+
+/// Overrides for the image `frontend --build --docker` produces. Any unset
+/// field falls back to a sensible default (see [`DockerConfig::tag`] and
+/// friends).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerConfig {
+    /// Defaults to `ethereum-boilerplate-frontend:latest` when unset.
+    pub image_tag: Option<String>,
+    /// Defaults to `nginx:alpine` when unset.
+    pub base_image: Option<String>,
+    /// Port the static file server listens on inside the container. Defaults to 80.
+    pub serve_port: Option<u16>,
+}
+
+impl DockerConfig {
+    pub fn tag(&self) -> &str {
+        self.image_tag.as_deref().unwrap_or("ethereum-boilerplate-frontend:latest")
+    }
+
+    pub fn base_image(&self) -> &str {
+        self.base_image.as_deref().unwrap_or("nginx:alpine")
+    }
+
+    pub fn serve_port(&self) -> u16 {
+        self.serve_port.unwrap_or(80)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +75,87 @@ pub struct NetworkConfig {
     pub explorer_url: String,
     pub native_currency: String,
     pub block_time: u64,
+    /// Additional fallback RPC endpoints tried, in order, after `rpc_url`
+    /// when it times out or errors. Empty by default; `rpc_url` alone is
+    /// still used in that case.
+    #[serde(default)]
+    pub rpc_urls: Vec<String>,
+    /// WebSocket endpoint for streaming `newHeads`/log subscriptions via
+    /// [`crate::subscription::LogSubscriber`]. Unset by default, since most
+    /// presets only ship an HTTP RPC URL.
+    #[serde(default)]
+    pub ws_rpc_url: Option<String>,
+    /// Optional fixed-cost/ceiling gas policy enforced before broadcasting
+    /// a transaction on this network.
+    #[serde(default)]
+    pub gas_policy: Option<GasPolicy>,
+}
+
+impl NetworkConfig {
+    /// The ordered list of endpoints to try: `rpc_url` first, then any
+    /// configured `rpc_urls` fallbacks (duplicates of `rpc_url` removed).
+    pub fn endpoints(&self) -> Vec<String> {
+        let mut endpoints = vec![self.rpc_url.clone()];
+        for url in &self.rpc_urls {
+            if url != &self.rpc_url {
+                endpoints.push(url.clone());
+            }
+        }
+        endpoints
+    }
+}
+
+/// Per-network gas policy enforced before any deploy or contract call is
+/// broadcast, so deploys on a silo/app-chain can behave deterministically
+/// regardless of live base fee.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasPolicy {
+    /// When set, every transaction on this network uses this flat gas
+    /// price (Wei, as a decimal string) instead of a live estimate.
+    pub fixed_gas_price: Option<String>,
+    /// Hard ceiling (Wei, as a decimal string) on the projected total fee
+    /// (`gas_limit * gas_price`) a transaction may spend, checked with the
+    /// fixed gas price when one is set. Exceeding it rejects the
+    /// transaction; no override is available.
+    pub fee_ceiling: Option<String>,
+}
+
+/// Outcome of checking a projected fee against a [`GasPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasPolicyDecision {
+    /// No policy configured; the caller's own value is used unchanged.
+    Unconstrained,
+    /// A fixed gas price substitutes the live estimate.
+    Fixed,
+    /// The projected fee is within the configured ceiling.
+    WithinCeiling,
+    /// The projected fee exceeds the configured ceiling; the transaction is
+    /// rejected.
+    CeilingExceeded,
+}
+
+impl GasPolicy {
+    /// Checks a projected total fee (in Wei) against this policy, returning
+    /// the gas price to actually use and the decision that was made.
+    pub fn enforce(&self, gas_limit: u64, live_gas_price_wei: u128) -> (u128, GasPolicyDecision) {
+        let fixed = self.fixed_gas_price.as_deref().and_then(|s| s.parse::<u128>().ok());
+        let gas_price = fixed.unwrap_or(live_gas_price_wei);
+
+        if let Some(ceiling) = self.fee_ceiling.as_deref().and_then(|s| s.parse::<u128>().ok()) {
+            let projected = gas_price.saturating_mul(gas_limit as u128);
+            if projected > ceiling {
+                return (gas_price, GasPolicyDecision::CeilingExceeded);
+            }
+            if fixed.is_none() {
+                return (gas_price, GasPolicyDecision::WithinCeiling);
+            }
+        }
+
+        match fixed {
+            Some(_) => (gas_price, GasPolicyDecision::Fixed),
+            None => (gas_price, GasPolicyDecision::Unconstrained),
+        }
+    }
 }
 
 impl Default for Config {
@@ -32,6 +167,10 @@ impl Default for Config {
             log_level: "info".to_string(),
             server_port: 3000,
             network: NetworkConfig::localhost(),
+            bench_results_url: None,
+            release: None,
+            docker: None,
+            max_rpc_retries: None,
         }
     }
 }
@@ -45,6 +184,9 @@ impl NetworkConfig {
             explorer_url: "".to_string(),
             native_currency: "ETH".to_string(),
             block_time: 2,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -56,6 +198,9 @@ impl NetworkConfig {
             explorer_url: "https://etherscan.io".to_string(),
             native_currency: "ETH".to_string(),
             block_time: 12,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -67,6 +212,9 @@ impl NetworkConfig {
             explorer_url: "https://sepolia.etherscan.io".to_string(),
             native_currency: "ETH".to_string(),
             block_time: 12,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -78,6 +226,9 @@ impl NetworkConfig {
             explorer_url: "https://polygonscan.com".to_string(),
             native_currency: "MATIC".to_string(),
             block_time: 2,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -89,6 +240,9 @@ impl NetworkConfig {
             explorer_url: "https://arbiscan.io".to_string(),
             native_currency: "ETH".to_string(),
             block_time: 1,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -100,6 +254,9 @@ impl NetworkConfig {
             explorer_url: "https://optimistic.etherscan.io".to_string(),
             native_currency: "ETH".to_string(),
             block_time: 2,
+            rpc_urls: Vec::new(),
+            ws_rpc_url: None,
+            gas_policy: None,
         }
     }
     
@@ -145,6 +302,10 @@ impl Config {
                 .parse()
                 .unwrap_or(3000),
             network: NetworkConfig::localhost(),
+            bench_results_url: std::env::var("BENCH_RESULTS_URL").ok(),
+            release: None,
+            docker: None,
+            max_rpc_retries: std::env::var("MAX_RPC_RETRIES").ok().and_then(|s| s.parse().ok()),
         })
     }
     
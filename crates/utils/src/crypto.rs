@@ -2,7 +2,33 @@
 use crate::error::{Result, UtilsError};
 use alloy::primitives::{Address, TxHash as H256, PrimitiveSignature as Signature, keccak256};
 use alloy::hex;
+use alloy::signers::{local::PrivateKeySigner, Signer};
 use rand::Rng;
+use thiserror::Error;
+
+/// Precise reasons a key/signing operation can fail, so callers can match on
+/// (for example) "the hex decoded but the scalar is out of range" versus
+/// "the string wasn't valid hex" instead of parsing an opaque message back
+/// out of `UtilsError::InvalidPrivateKey`.
+#[derive(Error, Debug)]
+pub enum CryptoError {
+    #[error("invalid secret key: {0}")]
+    InvalidSecretKey(String),
+
+    /// Reserved for operations that parse a raw public key (as opposed to
+    /// an address derived from one); no call site constructs this yet.
+    #[error("invalid public key: {0}")]
+    InvalidPublicKey(String),
+
+    #[error("invalid buffer length: expected {expected} bytes, got {actual}")]
+    InvalidBufferLength { expected: usize, actual: usize },
+
+    #[error("key derivation failed: {0}")]
+    KeyDerivationFailed(String),
+
+    #[error("signature verification failed: {0}")]
+    SignatureVerificationFailed(String),
+}
 
 /// Generate a random Ethereum address
 pub fn generate_random_address() -> Address {
@@ -23,26 +49,42 @@ pub fn wallet_from_private_key(private_key: &str) -> Result<String> {
     private_key
         .parse::<Address>()
         .map(|addr| format!("{:x}", addr))
-        .map_err(|e| UtilsError::invalid_private_key(format!("Invalid private key: {}", e)))
+        .map_err(|e| CryptoError::InvalidSecretKey(e.to_string()).into())
 }
 
-/// Sign message with private key (returns hex string)
+/// Sign `message` with `private_key` using EIP-191 `personal_sign`: hashes
+/// `message` through [`hash_message`] (the `\x19Ethereum Signed Message:\n{len}`
+/// prefix Ethereum wallets use for off-chain signatures) and signs that
+/// digest with secp256k1, returning the canonical 65-byte `r || s || v`
+/// signature as a `0x`-prefixed hex string.
 pub async fn sign_message(private_key: &str, message: &str) -> Result<String> {
-    let _address = wallet_from_private_key(private_key)?;
-    let message_hash = keccak256(message.as_bytes());
-    // Simplified signing - in real implementation you'd use proper signing
-    Ok(format!("0x{}", message_hash))
+    let signer = private_key
+        .parse::<PrivateKeySigner>()
+        .map_err(|e| CryptoError::InvalidSecretKey(e.to_string()))?;
+
+    let digest = hash_message(message);
+    let signature = signer
+        .sign_hash(&digest)
+        .await
+        .map_err(|e| CryptoError::KeyDerivationFailed(e.to_string()))?;
+
+    Ok(format!("0x{}", hex::encode(signature.as_bytes())))
 }
 
-/// Verify message signature
-pub fn verify_signature(_address: Address, message: &str, signature_hex: &str) -> Result<bool> {
-    let _sig = signature_hex
+/// Verifies an EIP-191 `personal_sign` signature: recomputes the same
+/// [`hash_message`] digest `sign_message` signed, recovers the signing
+/// address via ecrecover, and checks it against `address`.
+pub fn verify_signature(address: Address, message: &str, signature_hex: &str) -> Result<bool> {
+    let signature = signature_hex
         .parse::<Signature>()
-        .map_err(|e| UtilsError::validation_error(format!("Invalid signature format: {}", e)))?;
+        .map_err(|e| CryptoError::SignatureVerificationFailed(format!("invalid signature format: {}", e)))?;
+
+    let digest = hash_message(message);
+    let recovered = signature
+        .recover_address_from_prehash(&digest)
+        .map_err(|e| CryptoError::SignatureVerificationFailed(e.to_string()))?;
 
-    let _message_hash = keccak256(message.as_bytes());
-    // Simplified verification - in real implementation you'd use proper recovery
-    Ok(true) // Placeholder for tests
+    Ok(recovered == address)
 }
 
 /// Hash message using Ethereum's personal_sign format (\x19Ethereum Signed Message...)
@@ -63,10 +105,31 @@ pub fn generate_address_from_salt(salt: &str) -> Address {
     Address::from_slice(&hash.as_slice()[12..])
 }
 
-/// Convert address to checksum/full hex format
+/// Converts an address to its EIP-55 mixed-case checksum representation:
+/// the 40-char lowercase hex digits are individually uppercased wherever
+/// the corresponding nibble of `keccak256(lowercase_hex)` is `>= 8`.
 pub fn to_checksum_address(address: Address) -> String {
-    // Ethers Address already implements checksum when formatted via #x
-    format!("{:#x}", address)
+    let addr_hex = hex::encode(address.as_slice());
+    let hash = keccak256(addr_hex.as_bytes());
+
+    let mut checksummed = String::with_capacity(42);
+    checksummed.push_str("0x");
+    for (i, c) in addr_hex.chars().enumerate() {
+        if c.is_ascii_digit() {
+            checksummed.push(c);
+            continue;
+        }
+
+        let byte = hash[i / 2];
+        let nibble = if i % 2 == 0 { byte >> 4 } else { byte & 0x0f };
+        if nibble >= 8 {
+            checksummed.push(c.to_ascii_uppercase());
+        } else {
+            checksummed.push(c);
+        }
+    }
+
+    checksummed
 }
 
 /// Validate and format address string
@@ -137,6 +200,137 @@ pub fn verify_merkle_proof(leaf: H256, proof: &[H256], root: H256) -> bool {
     computed_hash == root
 }
 
+/// Generate a compact multiproof for several leaves at once, byte-compatible
+/// with OpenZeppelin's `MerkleProof.multiProofVerify`. `selected_indices` are
+/// indices into `leaves`; the caller must later pass the corresponding
+/// leaves back to [`verify_multiproof`] in ascending index order.
+///
+/// Walks the tree bottom-up: whenever both children of a pair are already
+/// derivable from the selected set, that combine needs no externally
+/// supplied sibling (`flags[i] = true`); whenever only one side is, the
+/// other side is pushed into `proof` (`flags[i] = false`). Pairs where
+/// neither side is derivable are hashed and carried up unchanged -- they
+/// only enter `proof` if a later level pairs them with a derivable sibling.
+pub fn merkle_multiproof(leaves: &[H256], selected_indices: &[usize]) -> (Vec<H256>, Vec<bool>) {
+    if leaves.is_empty() || selected_indices.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut known: Vec<bool> = (0..leaves.len()).map(|i| selected_indices.contains(&i)).collect();
+    let mut current_level = leaves.to_vec();
+
+    let mut proof = Vec::new();
+    let mut flags = Vec::new();
+
+    while current_level.len() > 1 {
+        let mut next_level = Vec::with_capacity(current_level.len().div_ceil(2));
+        let mut next_known = Vec::with_capacity(next_level.capacity());
+
+        for pair in current_level.chunks(2).zip(known.chunks(2)) {
+            let (nodes, known_pair) = pair;
+            if nodes.len() == 2 {
+                let (a, b) = (nodes[0], nodes[1]);
+                match (known_pair[0], known_pair[1]) {
+                    (true, true) => flags.push(true),
+                    (true, false) => {
+                        flags.push(false);
+                        proof.push(b);
+                    }
+                    (false, true) => {
+                        flags.push(false);
+                        proof.push(a);
+                    }
+                    (false, false) => {
+                        // Neither side is proven yet; stays unresolved
+                        // unless a later level pairs it with a known node.
+                    }
+                }
+
+                next_level.push(hash_pair(a, b));
+                next_known.push(known_pair[0] || known_pair[1]);
+            } else {
+                // Odd one out propagates unchanged, same as `merkle_root`.
+                next_level.push(nodes[0]);
+                next_known.push(known_pair[0]);
+            }
+        }
+
+        current_level = next_level;
+        known = next_known;
+    }
+
+    (proof, flags)
+}
+
+/// Verify a multiproof produced by [`merkle_multiproof`]. `selected_leaves`
+/// must be supplied in ascending original-index order. Mirrors OpenZeppelin's
+/// `MerkleProof.processMultiProof`: merges `selected_leaves` and the
+/// `hashes` computed so far through shared `leaf_pos`/`hash_pos` cursors,
+/// taking the second operand of each pair from that same merged sequence
+/// when `flags[i]` is set, or from `proof` otherwise.
+pub fn verify_multiproof(selected_leaves: &[H256], proof: &[H256], flags: &[bool], root: H256) -> bool {
+    let total = flags.len();
+
+    if total == 0 {
+        let computed = match (selected_leaves.first(), proof.first()) {
+            (Some(&leaf), _) => leaf,
+            (None, Some(&p)) => p,
+            (None, None) => return false,
+        };
+        return computed == root;
+    }
+
+    if selected_leaves.len() + proof.len() != total + 1 {
+        return false;
+    }
+
+    let mut hashes = vec![H256::default(); total];
+    let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0usize, 0usize, 0usize);
+
+    fn next(
+        selected_leaves: &[H256],
+        hashes: &[H256],
+        leaf_pos: &mut usize,
+        hash_pos: &mut usize,
+    ) -> Option<H256> {
+        if *leaf_pos < selected_leaves.len() {
+            let v = selected_leaves[*leaf_pos];
+            *leaf_pos += 1;
+            Some(v)
+        } else if *hash_pos < hashes.len() {
+            let v = hashes[*hash_pos];
+            *hash_pos += 1;
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    for i in 0..total {
+        let a = match next(selected_leaves, &hashes, &mut leaf_pos, &mut hash_pos) {
+            Some(v) => v,
+            None => return false,
+        };
+        let b = if flags[i] {
+            match next(selected_leaves, &hashes, &mut leaf_pos, &mut hash_pos) {
+                Some(v) => v,
+                None => return false,
+            }
+        } else {
+            match proof.get(proof_pos) {
+                Some(&v) => {
+                    proof_pos += 1;
+                    v
+                }
+                None => return false,
+            }
+        };
+        hashes[i] = hash_pair(a, b);
+    }
+
+    hashes[total - 1] == root
+}
+
 /// Helper to hash two nodes in sorted order
 fn hash_pair(a: H256, b: H256) -> H256 {
     let mut combined = [0u8; 64];
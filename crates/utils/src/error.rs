@@ -1,5 +1,6 @@
 //! Common error types and utilities
 
+use alloy::primitives::Bytes;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, UtilsError>;
@@ -8,16 +9,35 @@ pub type Result<T> = std::result::Result<T, UtilsError>;
 pub enum UtilsError {
     #[error("Configuration error: {0}")]
     Config(String),
-    
+
     #[error("Validation error: {0}")]
     Validation(String),
-    
+
     #[error("Network error: {0}")]
-    Network(#[from] reqwest::Error),
-    
+    Network(String),
+
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("RPC error {code}: {message}")]
+    Rpc {
+        code: i64,
+        message: String,
+        data: Option<Bytes>,
+    },
+
+    #[error("Transaction failed: {0}")]
+    Transaction(TransactionFailure),
+
     #[error("Ethereum error: {0}")]
     Ethereum(String),
-    
+
+    #[error("Crypto error: {0}")]
+    Crypto(#[from] crate::crypto::CryptoError),
+
     #[error("Signer error: {0}")]
     Signer(#[from] alloy::signers::local::LocalSignerError),
     
@@ -53,17 +73,102 @@ pub enum UtilsError {
     
     #[error("Internal error: {0}")]
     Internal(String),
+
+    /// Built by [`UtilsError::with_context`]: wraps an existing error
+    /// behind a short message while preserving it as `source()` (instead of
+    /// flattening it into a string the way `From<anyhow::Error>` does), plus
+    /// a `Backtrace` captured at the wrap site.
+    #[error("{message}")]
+    Context {
+        message: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
+        backtrace: Box<std::backtrace::Backtrace>,
+    },
+}
+
+/// Why a submitted transaction failed, distinct from why it couldn't be
+/// submitted in the first place (see `UtilsError::Network`/`Timeout`).
+#[derive(Debug, Clone)]
+pub enum TransactionFailure {
+    /// The call reverted. `reason` is the decoded `Error(string)` message
+    /// when one was present (see `decode_revert_reason`); `data` is the raw
+    /// revert bytes returned by the node.
+    Reverted {
+        data: Option<Bytes>,
+        reason: Option<String>,
+    },
+    OutOfGas,
+    Other(String),
+}
+
+impl std::fmt::Display for TransactionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reverted { reason: Some(reason), .. } => write!(f, "reverted: {}", reason),
+            Self::Reverted { reason: None, .. } => write!(f, "reverted (no reason given)"),
+            Self::OutOfGas => write!(f, "out of gas"),
+            Self::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Decodes a revert reason from ABI-encoded `Error(string)` call data
+/// (selector `0x08c379a0`), the form `revert("...")`/`require(cond, "...")`
+/// messages take on-chain.
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+    if data.len() < 4 || data[..4] != ERROR_SELECTOR {
+        return None;
+    }
+
+    let payload = &data[4..];
+    // Word 1 is the string's offset (always 0x20 for a single dynamic arg);
+    // word 2 is its byte length; the string bytes follow, right-padded.
+    if payload.len() < 64 {
+        return None;
+    }
+    let len = u32::from_be_bytes(payload[60..64].try_into().ok()?) as usize;
+    let bytes = payload.get(64..64 + len)?;
+    String::from_utf8(bytes.to_vec()).ok()
 }
 
 impl UtilsError {
     pub fn config_error(msg: impl Into<String>) -> Self {
         Self::Config(msg.into())
     }
-    
+
     pub fn validation_error(msg: impl Into<String>) -> Self {
         Self::Validation(msg.into())
     }
-    
+
+    pub fn timeout(msg: impl Into<String>) -> Self {
+        Self::Timeout(msg.into())
+    }
+
+    pub fn rate_limited(msg: impl Into<String>) -> Self {
+        Self::RateLimited(msg.into())
+    }
+
+    pub fn rpc(code: i64, message: impl Into<String>, data: Option<Bytes>) -> Self {
+        Self::Rpc { code, message: message.into(), data }
+    }
+
+    /// Builds a `Transaction(Reverted)` error, decoding a revert string out
+    /// of `data` (the RPC `error.data` field) when present.
+    pub fn reverted(data: Option<Bytes>) -> Self {
+        let reason = data.as_ref().and_then(|d| decode_revert_reason(d));
+        Self::Transaction(TransactionFailure::Reverted { data, reason })
+    }
+
+    pub fn out_of_gas() -> Self {
+        Self::Transaction(TransactionFailure::OutOfGas)
+    }
+
+    pub fn transaction_error(msg: impl Into<String>) -> Self {
+        Self::Transaction(TransactionFailure::Other(msg.into()))
+    }
+
     pub fn parse(msg: impl Into<String>) -> Self {
         Self::Parse(msg.into())
     }
@@ -92,8 +197,8 @@ impl UtilsError {
         Self::Database(msg.into())
     }
     
-    pub fn network(error: reqwest::Error) -> Self {
-        Self::Network(error)
+    pub fn network(error: impl std::fmt::Display) -> Self {
+        Self::Network(error.to_string())
     }
     
     pub fn interactive_error(msg: impl Into<String>) -> Self {
@@ -105,9 +210,204 @@ impl UtilsError {
     }
 }
 
-// Automatic conversion from anyhow::Error
+/// How [`UtilsError::report`] prints an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Colored, human-readable -- reuses the CLI's existing `print_error`.
+    Pretty,
+    /// `{ "kind", "code", "message" }` on stdout, for scripts/CI to parse.
+    Json,
+}
+
+#[derive(serde::Serialize)]
+struct ErrorReport {
+    kind: &'static str,
+    code: i32,
+    message: String,
+}
+
+impl UtilsError {
+    /// Stable per-category process exit code, so wrapper scripts/CI can
+    /// branch on exit status instead of scraping stderr. Stable across
+    /// refactors of the variant list itself -- new variants should join the
+    /// closest existing category rather than claim a new code.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Config(_) => 2,
+            Self::Validation(_)
+            | Self::InvalidAddress(_)
+            | Self::InvalidPrivateKey(_)
+            | Self::InvalidAmount(_)
+            | Self::Parse(_) => 3,
+            Self::Network(_) | Self::Timeout(_) | Self::RateLimited(_) | Self::UnsupportedNetwork(_) => 4,
+            Self::Ethereum(_) | Self::Contract(_) | Self::Rpc { .. } | Self::Transaction(_) => 5,
+            Self::Crypto(_) | Self::Signer(_) => 6,
+            Self::Database(_) => 7,
+            Self::Serialization(_) | Self::Io(_) => 8,
+            Self::Interactive(_) => 9,
+            Self::Internal(_) => 1,
+            // Contextual wrapping shouldn't change how a caller classifies
+            // the underlying failure, so delegate when the source is itself
+            // a `UtilsError`.
+            Self::Context { source, .. } => source
+                .downcast_ref::<UtilsError>()
+                .map(|e| e.exit_code())
+                .unwrap_or(1),
+        }
+    }
+
+    /// Machine-readable category label used by [`UtilsError::report`]'s
+    /// JSON output, kept stable even if a variant is later renamed.
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "config",
+            Self::Validation(_) => "validation",
+            Self::Network(_) => "network",
+            Self::Timeout(_) => "timeout",
+            Self::RateLimited(_) => "rate_limited",
+            Self::Rpc { .. } => "rpc",
+            Self::Transaction(_) => "transaction",
+            Self::Ethereum(_) => "ethereum",
+            Self::Crypto(_) => "crypto",
+            Self::Signer(_) => "signer",
+            Self::Serialization(_) => "serialization",
+            Self::Io(_) => "io",
+            Self::Parse(_) => "parse",
+            Self::InvalidAddress(_) => "invalid_address",
+            Self::InvalidPrivateKey(_) => "invalid_private_key",
+            Self::InvalidAmount(_) => "invalid_amount",
+            Self::UnsupportedNetwork(_) => "unsupported_network",
+            Self::Contract(_) => "contract",
+            Self::Database(_) => "database",
+            Self::Interactive(_) => "interactive",
+            Self::Internal(_) => "internal",
+            Self::Context { source, .. } => source
+                .downcast_ref::<UtilsError>()
+                .map(|e| e.kind())
+                .unwrap_or("context"),
+        }
+    }
+
+    /// Wraps `self` with additional context, preserving it as the
+    /// `source()` of the returned error rather than collapsing it into a
+    /// string. Captures a [`std::backtrace::Backtrace`] at the call site --
+    /// empty unless `RUST_BACKTRACE` is set, per `Backtrace::capture`.
+    pub fn with_context(self, msg: impl Into<String>) -> Self {
+        Self::Context {
+            message: msg.into(),
+            source: Box::new(self),
+            backtrace: Box::new(std::backtrace::Backtrace::capture()),
+        }
+    }
+
+    /// The backtrace captured by [`UtilsError::with_context`], if this
+    /// error (or one it wraps) was built that way.
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        match self {
+            Self::Context { backtrace, source, .. } => Some(
+                source
+                    .downcast_ref::<UtilsError>()
+                    .and_then(|e| e.backtrace())
+                    .unwrap_or(backtrace),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Walks the full `source()` chain -- one line per link -- followed by
+    /// the captured backtrace, if any: the "where did this actually
+    /// originate" view that `Display`'s short message deliberately doesn't
+    /// show.
+    pub fn detailed(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let _ = writeln!(out, "{}", self);
+
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            let _ = writeln!(out, "caused by: {}", err);
+            source = err.source();
+        }
+
+        if let Some(bt) = self.backtrace() {
+            let _ = writeln!(out, "backtrace:\n{}", bt);
+        }
+
+        out
+    }
+
+    /// Prints this error per `format`: `Pretty` reuses the same colored
+    /// `print_error` the rest of the CLI already calls; `Json` emits
+    /// `{ "kind", "code", "message" }` on stdout for scripts/CI to parse.
+    pub fn report(&self, format: ReportFormat) {
+        match format {
+            ReportFormat::Pretty => crate::print_error(&self.to_string()),
+            ReportFormat::Json => {
+                let report = ErrorReport {
+                    kind: self.kind(),
+                    code: self.exit_code(),
+                    message: self.to_string(),
+                };
+                match serde_json::to_string(&report) {
+                    Ok(json) => println!("{}", json),
+                    Err(_) => crate::print_error(&self.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// One link in an `anyhow::Error`'s cause chain, reconstructed as an owned
+/// `std::error::Error` so it can be boxed into [`UtilsError::Context`]'s
+/// `source` field without losing the individual messages -- unlike
+/// `err.to_string()`, which flattens the whole chain into a single line.
+#[derive(Debug)]
+struct AnyhowChainLink {
+    message: String,
+    source: Option<Box<AnyhowChainLink>>,
+}
+
+impl std::fmt::Display for AnyhowChainLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AnyhowChainLink {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|s| s as &(dyn std::error::Error + 'static))
+    }
+}
+
+// Automatic conversion from anyhow::Error, preserving its full cause chain
+// (rather than collapsing it with `err.to_string()`) so `UtilsError::detailed`
+// can still walk it one link at a time.
 impl From<anyhow::Error> for UtilsError {
     fn from(err: anyhow::Error) -> Self {
-        Self::Internal(err.to_string())
+        let mut links: Vec<String> = err.chain().map(|cause| cause.to_string()).collect();
+
+        let mut source: Option<Box<AnyhowChainLink>> = None;
+        while let Some(message) = links.pop() {
+            source = Some(Box::new(AnyhowChainLink { message, source }));
+        }
+
+        match source {
+            Some(chain) => Self::Context {
+                message: chain.message.clone(),
+                source: chain,
+                backtrace: Box::new(std::backtrace::Backtrace::capture()),
+            },
+            None => Self::Internal("unknown error".to_string()),
+        }
+    }
+}
+
+// Automatic conversion from reqwest::Error (kept manual rather than
+// `#[from]` on the variant since `Network` now carries a plain message
+// shared with the non-reqwest `network_error` helper).
+impl From<reqwest::Error> for UtilsError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Network(err.to_string())
     }
 }
@@ -7,14 +7,14 @@ pub fn to_parse_error<E: std::fmt::Display>(error: E) -> UtilsError {
     UtilsError::parse(error.to_string())
 }
 
-/// Convert a timeout into `UtilsError::Parse`
+/// Convert a timeout into `UtilsError::Timeout`
 pub fn timeout_error() -> UtilsError {
-    UtilsError::parse("Timeout")
+    UtilsError::timeout("Timeout")
 }
 
-/// Convert a generic request error into `UtilsError::Parse`
+/// Convert a generic request error into `UtilsError::Network`
 pub fn request_error() -> UtilsError {
-    UtilsError::parse("Request error")
+    UtilsError::network("Request error")
 }
 
 /// Convert a validation error into `UtilsError::Validation`
@@ -27,11 +27,9 @@ pub fn config_error<E: std::fmt::Display>(error: E) -> UtilsError {
     UtilsError::config_error(error.to_string())
 }
 
-/// Convert a network-related error into a `UtilsError` variant.
-/// Note: constructing `reqwest::Error` from arbitrary types isn't possible here,
-/// so we map network issues to a parse/diagnostic error for now.
+/// Convert a network-related error into `UtilsError::Network`
 pub fn network_error<E: std::fmt::Display>(error: E) -> UtilsError {
-    UtilsError::parse(format!("Network error: {}", error))
+    UtilsError::network(error)
 }
 
 /// Map a `Result<T, E>` into the crate `Result<T>` using `to_parse_error`
@@ -3,10 +3,33 @@
 use alloy::primitives::U256;
 use ethereum_boilerplate_shared::{datetime::DEFAULT_FORMAT, datetime::INVALID_TIMESTAMP};
 
+/// Renders `value / 10^decimals` as a fixed-point string with exactly
+/// `precision` fractional digits (zero-padded or truncated as needed),
+/// reusing [`crate::validation::format_units`]'s exact integer division for
+/// the whole/fractional split -- that function trims trailing zeros for a
+/// "nicest" display, which `format_wei`/`format_gas_price` can't use
+/// directly since their callers expect a fixed width (e.g. always 6 digits
+/// for ETH). Still never touches `f64`.
+fn format_fixed(value: U256, decimals: u8, precision: usize) -> String {
+    let exact = crate::validation::format_units(value, decimals);
+    let (whole, frac) = exact.split_once('.').unwrap_or((exact.as_str(), ""));
+
+    if precision == 0 {
+        return whole.to_string();
+    }
+
+    let frac = if frac.len() >= precision {
+        frac[..precision].to_string()
+    } else {
+        format!("{:0<width$}", frac, width = precision)
+    };
+
+    format!("{}.{}", whole, frac)
+}
+
 /// Formats Wei amount to ETH string
 pub fn format_wei(wei: U256) -> String {
-    let wei_f64 = wei.to_string().parse::<f64>().unwrap_or(0.0);
-    format!("{:.6}", wei_f64 / 1e18)
+    format_fixed(wei, 18, 6)
 }
 
 /// Formats ETH amount to readable string
@@ -16,9 +39,7 @@ pub fn format_eth(eth: f64) -> String {
 
 /// Parses ETH string to Wei
 pub fn parse_eth(eth: &str) -> Result<U256, Box<dyn std::error::Error>> {
-    let eth_value: f64 = eth.parse()?;
-    let wei_value = (eth_value * 1e18) as u128;
-    Ok(U256::from(wei_value))
+    crate::validation::parse_units(eth, 18).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
 }
 
 /// Parses Wei string to U256
@@ -46,8 +67,7 @@ pub fn format_tx_hash(hash: &str) -> String {
 
 /// Formats gas price in Gwei
 pub fn format_gas_price(gas_price: U256) -> String {
-    let gwei = gas_price.to::<u128>() as f64 / 1_000_000_000.0;
-    format!("{:.2} Gwei", gwei)
+    format!("{} Gwei", format_fixed(gas_price, 9, 2))
 }
 
 /// Formats block number
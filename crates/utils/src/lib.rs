@@ -12,13 +12,14 @@ pub mod validation;
 pub mod config;
 pub mod logging;
 pub mod network;
+pub mod subscription;
 pub mod async_utils;
 
 // Re-export commonly used items
 pub use crate::error::{Result, UtilsError};
 pub use crate::config::Config;
 pub use crate::logging::init_logging;
-pub use crate::validation::{validate_address, validate_private_key, validate_amount};
+pub use crate::validation::{validate_address, validate_address_checksummed, validate_private_key, validate_amount, parse_units, format_units};
 pub use crate::formatting::{format_eth, format_wei, parse_eth, parse_wei, format_address_display};
 
 
@@ -1,42 +1,78 @@
 //! Logging utilities
+//!
+//! Installs a real `tracing_subscriber` pipeline instead of just
+//! `println!`ing: human-readable colored output for development, structured
+//! JSON for production (so CI/log-aggregation can parse it), and an
+//! `EnvFilter` wrapped in a `reload::Handle` so `set_log_level` can actually
+//! reconfigure the live filter instead of only mutating an env var.
 
-use crate::error::Result;
+use crate::error::{Result, UtilsError};
+use std::sync::OnceLock;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{fmt, layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter, Registry};
 
-/// Initialize logging with default level
-pub fn init_logging(level: &str) -> Result<()> {
-    let level = match level {
-        "debug" => tracing::Level::DEBUG,
-        "trace" => tracing::Level::TRACE,
-        "info" => tracing::Level::INFO,
-        "warn" => tracing::Level::WARN,
-        "error" => tracing::Level::ERROR,
-        _ => tracing::Level::INFO,
+type ReloadHandle = reload::Handle<EnvFilter, Registry>;
+
+static FILTER_HANDLE: OnceLock<ReloadHandle> = OnceLock::new();
+
+fn level_directive(level: &str) -> LevelFilter {
+    match level {
+        "debug" => LevelFilter::DEBUG,
+        "trace" => LevelFilter::TRACE,
+        "warn" => LevelFilter::WARN,
+        "error" => LevelFilter::ERROR,
+        _ => LevelFilter::INFO,
+    }
+}
+
+fn env_filter(default_level: &str) -> EnvFilter {
+    EnvFilter::builder()
+        .with_default_directive(level_directive(default_level).into())
+        .from_env_lossy()
+}
+
+/// Installs the global subscriber with the given filter, storing its
+/// `reload::Handle` so `set_log_level` can swap the filter later. Only the
+/// first call in a process wins, matching `tracing`'s single global
+/// subscriber.
+fn install(filter: EnvFilter, json: bool) -> Result<()> {
+    let (filter_layer, handle) = reload::Layer::new(filter);
+    let registry = Registry::default().with(filter_layer);
+
+    let result = if json {
+        registry.with(fmt::layer().json()).try_init()
+    } else {
+        registry.with(fmt::layer().with_target(false)).try_init()
     };
-    
-    // Simple subscriber without tracing_subscriber for now
-    std::println!("Logging initialized at level: {:?}", level);
+    result.map_err(|e| UtilsError::config_error(format!("Failed to install tracing subscriber: {}", e)))?;
+
+    FILTER_HANDLE
+        .set(handle)
+        .map_err(|_| UtilsError::config_error("Logging is already initialized"))?;
+
     Ok(())
 }
 
-/// Initialize logging for development
+/// Initialize logging at the given level (debug/trace/info/warn/error),
+/// with human-readable colored output.
+pub fn init_logging(level: &str) -> Result<()> {
+    install(env_filter(level), false)
+}
+
+/// Initialize logging for development: debug-and-above, human-readable.
 pub fn init_dev_logging() -> Result<()> {
-    // Simple dev logging without tracing_subscriber
-    std::println!("Dev logging initialized");
-    Ok(())
+    install(env_filter("debug"), false)
 }
 
-/// Initialize logging for production
+/// Initialize logging for production: info-and-above, structured JSON so
+/// log aggregation can parse it.
 pub fn init_prod_logging() -> Result<()> {
-    // Simple prod logging without tracing_subscriber
-    std::println!("Prod logging initialized");
-    Ok(())
+    install(env_filter("info"), true)
 }
 
-/// Initialize logging for testing
+/// Initialize logging for tests: debug-and-above, human-readable.
 pub fn init_test_logging() -> Result<()> {
-    // Simple test logging without tracing_subscriber
-    std::println!("Test logging initialized");
-    Ok(())
+    install(env_filter("debug"), false)
 }
 
 /// Get current log level
@@ -44,9 +80,14 @@ pub fn get_log_level() -> String {
     std::env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string())
 }
 
-/// Set log level
+/// Set log level, reconfiguring the live subscriber's filter via its
+/// `reload::Handle` in addition to updating `LOG_LEVEL` (so `get_log_level`
+/// stays consistent for processes that haven't called `init_logging` yet).
 pub fn set_log_level(level: &str) {
     std::env::set_var("LOG_LEVEL", level);
+    if let Some(handle) = FILTER_HANDLE.get() {
+        let _ = handle.reload(env_filter(level));
+    }
 }
 
 /// Check if debug logging is enabled
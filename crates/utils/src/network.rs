@@ -2,9 +2,20 @@
 
 use alloy::providers::{Provider, ReqwestProvider};
 use alloy::primitives::{Address, U256};
+use futures::future::BoxFuture;
+use rand::Rng;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex as AsyncMutex, OnceCell};
+use tokio::time::sleep;
+use crate::async_utils::{CircuitBreaker, Throttler};
+use ethereum_boilerplate_shared::network::{DEFAULT_TIMEOUT_SECS, INITIAL_DELAY_MS, MAX_RETRIES};
+use ethereum_boilerplate_shared::errors::{NETWORK_TIMEOUT, MAX_RETRIES_EXCEEDED as MAX_RETRIES_EXCEEDED_MSG};
+use ethereum_boilerplate_shared::utils::{backoff_initial_delay, network_timeout_duration, network_timeout_error, max_retries_exceeded_error};
 use crate::error::{Result, UtilsError};
-use crate::config::NetworkConfig;
+use crate::config::{Config, NetworkConfig};
 
 /// Helper for converting provider errors to UtilsError::Ethereum format
 fn map_eth_err<E: std::fmt::Display>(e: E) -> UtilsError {
@@ -45,17 +56,1074 @@ pub async fn check_provider_health(provider: &ReqwestProvider) -> Result<()> {
     Ok(())
 }
 
-/// Get contract creation block 
+/// Finds the block the contract at `address` was deployed in by binary
+/// search, the way provider libraries like ethers do it: first confirm code
+/// is present at `latest` (otherwise `address` is an EOA or self-destructed
+/// contract, so there's no creation block to find), then narrow
+/// `[1, latest]` by checking `get_code_at` pinned to the midpoint block —
+/// empty code means the contract didn't exist yet, so the creation block is
+/// later; non-empty means it already existed, so it's at or before the
+/// midpoint. `O(log latest)` RPC calls instead of guessing.
 pub async fn get_contract_creation_block(
     provider: &ReqwestProvider,
     address: Address,
 ) -> Result<u64> {
-    let code = provider.get_code_at(address).await.map_err(map_eth_err)?;
-    
-    if !code.is_empty() {
-        let latest_block = provider.get_block_number().await.map_err(map_eth_err)?;
-        Ok(latest_block)
+    // Archive nodes serving the binary search below are frequently
+    // rate-limited under load; retry each call with a deadline instead of
+    // letting one transient hiccup fail the whole search.
+    let policy = RetryPolicy::default();
+
+    let latest = with_retry(policy, || async { provider.get_block_number().await.map_err(map_eth_err) }).await?;
+
+    let latest_code = with_retry(policy, || async { provider.get_code_at(address).await.map_err(map_eth_err) }).await?;
+    if latest_code.is_empty() {
+        return Ok(0);
+    }
+
+    let mut lo = 1u64;
+    let mut hi = latest;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let code = with_retry(policy, || async move {
+            provider
+                .get_code_at(address)
+                .block_id(alloy::eips::BlockId::number(mid))
+                .await
+                .map_err(|e| {
+                    UtilsError::Ethereum(format!(
+                        "Failed to query code at block {}: {} (this RPC endpoint may need to be an archive node to serve historical state)",
+                        mid, e
+                    ))
+                })
+        }).await?;
+
+        if code.is_empty() {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(lo)
+}
+
+/// A pool of RPC endpoints (`NetworkConfig::endpoints()`) that fails over
+/// to the next URL when one times out past `DEFAULT_TIMEOUT_SECS` or
+/// errors, retrying each endpoint with an `INITIAL_DELAY_MS`-doubling
+/// exponential backoff up to `MAX_RETRIES` times (`MAX_RETRIES + 1` attempts
+/// in all) before giving up on it.
+pub struct ProviderPool {
+    endpoints: Vec<String>,
+    chain_id: u64,
+}
+
+impl ProviderPool {
+    pub fn new(network: &NetworkConfig) -> Self {
+        Self {
+            endpoints: network.endpoints(),
+            chain_id: network.chain_id,
+        }
+    }
+
+    /// Returns a connected provider for the first endpoint that responds,
+    /// retrying each with exponential backoff before failing over to the
+    /// next. Fails only once every endpoint has exhausted its retries.
+    pub async fn connect(&self) -> Result<Arc<ReqwestProvider>> {
+        let mut last_err = UtilsError::network("No RPC endpoints configured");
+
+        for url in &self.endpoints {
+            match self.try_endpoint(url).await {
+                Ok(provider) => return Ok(provider),
+                Err(e) => last_err = e,
+            }
+        }
+
+        Err(last_err)
+    }
+
+    async fn try_endpoint(&self, url: &str) -> Result<Arc<ReqwestProvider>> {
+        let mut delay_ms = INITIAL_DELAY_MS;
+
+        for attempt in 0..=MAX_RETRIES {
+            let parsed = url
+                .parse()
+                .map_err(|e| UtilsError::config_error(format!("Invalid RPC URL: {}", e)))?;
+            let provider = Arc::new(ReqwestProvider::new_http(parsed));
+
+            let probe = tokio::time::timeout(
+                Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+                provider.get_chain_id(),
+            )
+            .await;
+
+            match probe {
+                Ok(Ok(_)) => return Ok(provider),
+                Ok(Err(_)) if attempt < MAX_RETRIES => {
+                    sleep(Duration::from_millis(delay_ms)).await;
+                    delay_ms *= 2;
+                }
+                Ok(Err(e)) => return Err(map_eth_err(e)),
+                Err(_) if attempt < MAX_RETRIES => {
+                    sleep(Duration::from_millis(delay_ms)).await;
+                    delay_ms *= 2;
+                }
+                Err(_) => {
+                    return Err(UtilsError::timeout(format!(
+                        "{} ('{}', {}s)",
+                        NETWORK_TIMEOUT, url, DEFAULT_TIMEOUT_SECS
+                    )))
+                }
+            }
+        }
+
+        Err(UtilsError::network(MAX_RETRIES_EXCEEDED_MSG))
+    }
+
+    /// Probes every endpoint with an `eth_chainId` call and returns the
+    /// first one that responds with the chain id this pool was configured
+    /// for, rejecting any endpoint that reports a different chain.
+    pub async fn healthy_endpoint(&self) -> Result<String> {
+        for url in &self.endpoints {
+            let parsed = match url.parse() {
+                Ok(url) => url,
+                Err(_) => continue,
+            };
+            let provider = ReqwestProvider::new_http(parsed);
+
+            let probe = tokio::time::timeout(
+                Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+                provider.get_chain_id(),
+            )
+            .await;
+
+            match probe {
+                Ok(Ok(chain_id)) if chain_id == self.chain_id => return Ok(url.clone()),
+                Ok(Ok(chain_id)) => {
+                    continue_with_mismatch(url, chain_id, self.chain_id);
+                }
+                _ => continue,
+            }
+        }
+
+        Err(UtilsError::network(format!(
+            "No healthy RPC endpoint found for chain id {}",
+            self.chain_id
+        )))
+    }
+}
+
+fn continue_with_mismatch(url: &str, got: u64, expected: u64) {
+    tracing::warn!(
+        "RPC endpoint '{}' reports chain id {} but network is configured for {}; skipping",
+        url,
+        got,
+        expected
+    );
+}
+
+// --- Provider middleware stack ---
+//
+// Mirrors ethers-rs's stackable middleware pattern (Provider -> GasOracle ->
+// NonceManager -> Signer): each layer delegates the read methods it doesn't
+// care about to the one underneath, and overrides only what it's
+// responsible for. `ProviderLike` is the shared surface so callers can hold
+// a bare `Arc<ReqwestProvider>` or a fully decorated stack interchangeably.
+
+/// Async read surface shared by the bare provider and every [`ProviderStack`]
+/// layer.
+pub trait ProviderLike: Send + Sync {
+    fn get_chain_id(&self) -> impl Future<Output = Result<u64>> + Send;
+    fn get_block_number(&self) -> impl Future<Output = Result<u64>> + Send;
+    fn get_gas_price(&self) -> impl Future<Output = Result<U256>> + Send;
+}
+
+impl ProviderLike for ReqwestProvider {
+    async fn get_chain_id(&self) -> Result<u64> {
+        get_chain_id(self).await
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        get_latest_block_number(self).await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256> {
+        get_gas_price(self).await
+    }
+}
+
+impl<T: ProviderLike> ProviderLike for Arc<T> {
+    async fn get_chain_id(&self) -> Result<u64> {
+        (**self).get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        (**self).get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256> {
+        (**self).get_gas_price().await
+    }
+}
+
+/// A gas price override, called fresh on every `get_gas_price()` so it can
+/// source a live quote (e.g. an `eth_feeHistory` percentile or an external
+/// gas station API).
+pub type GasPriceFn = Arc<dyn Fn() -> BoxFuture<'static, Result<U256>> + Send + Sync>;
+
+/// Caches the account nonce locally and hands out `nonce + 1` for each
+/// dispatched transaction, so a batch of concurrent sends doesn't collide on
+/// the same on-chain nonce. Delegates every `ProviderLike` read to `inner`
+/// unchanged.
+pub struct NonceManagerLayer<L> {
+    inner: L,
+    base: Arc<ReqwestProvider>,
+    cached: AtomicU64,
+    primed: AtomicBool,
+}
+
+impl<L: ProviderLike> NonceManagerLayer<L> {
+    pub fn new(inner: L, base: Arc<ReqwestProvider>) -> Self {
+        Self {
+            inner,
+            base,
+            cached: AtomicU64::new(0),
+            primed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the next nonce to use, priming the cache from
+    /// `eth_getTransactionCount` on first use.
+    pub async fn next_nonce(&self, address: Address) -> Result<u64> {
+        if !self.primed.swap(true, Ordering::SeqCst) {
+            let onchain = self.base.get_transaction_count(address).await.map_err(map_eth_err)?;
+            self.cached.store(onchain, Ordering::SeqCst);
+            return Ok(onchain);
+        }
+
+        Ok(self.cached.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Drops the local cache, forcing the next call to re-fetch from the
+    /// node. Use this after a nonce-related RPC rejection (e.g. "nonce too
+    /// low").
+    pub fn reset(&self) {
+        self.primed.store(false, Ordering::SeqCst);
+        self.cached.store(0, Ordering::SeqCst);
+    }
+}
+
+impl<L: ProviderLike> ProviderLike for NonceManagerLayer<L> {
+    async fn get_chain_id(&self) -> Result<u64> {
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        self.inner.get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256> {
+        self.inner.get_gas_price().await
+    }
+}
+
+/// Overrides `get_gas_price()` with a caller-supplied quote instead of the
+/// inner provider's `eth_gasPrice`. Every other read passes through
+/// unchanged.
+pub struct GasOracleLayer<L> {
+    inner: L,
+    price_fn: GasPriceFn,
+}
+
+impl<L: ProviderLike> GasOracleLayer<L> {
+    pub fn new(inner: L, price_fn: GasPriceFn) -> Self {
+        Self { inner, price_fn }
+    }
+}
+
+impl<L: ProviderLike> ProviderLike for GasOracleLayer<L> {
+    async fn get_chain_id(&self) -> Result<u64> {
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_number(&self) -> Result<u64> {
+        self.inner.get_block_number().await
+    }
+
+    async fn get_gas_price(&self) -> Result<U256> {
+        (self.price_fn)().await
+    }
+}
+
+/// Builds a decorated provider by stacking opt-in layers on top of a base
+/// `Arc<ReqwestProvider>`. Every layer (and the base) implements
+/// [`ProviderLike`], so existing `create_provider` call sites don't need to
+/// change to benefit from one.
+///
+/// ```ignore
+/// let stack = ProviderStack::new(create_provider(&network)?)
+///     .with_gas_oracle(price_fn)
+///     .with_nonce_manager()
+///     .build();
+/// ```
+pub struct ProviderStack<L> {
+    layer: L,
+    base: Arc<ReqwestProvider>,
+}
+
+impl ProviderStack<Arc<ReqwestProvider>> {
+    pub fn new(provider: Arc<ReqwestProvider>) -> Self {
+        Self { layer: provider.clone(), base: provider }
+    }
+}
+
+impl<L: ProviderLike> ProviderStack<L> {
+    pub fn with_nonce_manager(self) -> ProviderStack<NonceManagerLayer<L>> {
+        ProviderStack {
+            layer: NonceManagerLayer::new(self.layer, self.base.clone()),
+            base: self.base,
+        }
+    }
+
+    pub fn with_gas_oracle(self, price_fn: GasPriceFn) -> ProviderStack<GasOracleLayer<L>> {
+        ProviderStack {
+            layer: GasOracleLayer::new(self.layer, price_fn),
+            base: self.base,
+        }
+    }
+
+    pub fn build(self) -> L {
+        self.layer
+    }
+}
+
+// --- Quorum provider ---
+
+/// How much agreement a [`QuorumProvider`] requires among its endpoints'
+/// responses before trusting one.
+#[derive(Debug, Clone, Copy)]
+pub enum QuorumPolicy {
+    /// More than half of the endpoints must agree.
+    Majority,
+    /// Every endpoint must agree.
+    All,
+    /// At least this fraction (0.0-1.0) of endpoints must agree.
+    Percentage(f64),
+}
+
+impl QuorumPolicy {
+    fn required(&self, total: usize) -> usize {
+        match self {
+            QuorumPolicy::All => total,
+            QuorumPolicy::Majority => total / 2 + 1,
+            QuorumPolicy::Percentage(pct) => ((total as f64) * pct).ceil() as usize,
+        }
+    }
+}
+
+/// Fans each read call out to several RPC endpoints in parallel and only
+/// trusts the answer once the configured [`QuorumPolicy`] weight of
+/// endpoints agree on it, protecting against a single lying or lagging
+/// node.
+pub struct QuorumProvider {
+    providers: Vec<Arc<ReqwestProvider>>,
+    policy: QuorumPolicy,
+}
+
+impl QuorumProvider {
+    pub fn new(urls: &[String], policy: QuorumPolicy) -> Result<Self> {
+        let providers = urls
+            .iter()
+            .map(|url| {
+                let parsed = url
+                    .parse()
+                    .map_err(|e| UtilsError::config_error(format!("Invalid RPC URL: {}", e)))?;
+                Ok(Arc::new(ReqwestProvider::new_http(parsed)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { providers, policy })
+    }
+
+    pub async fn get_chain_id(&self) -> Result<u64> {
+        let futures = self
+            .providers
+            .iter()
+            .cloned()
+            .map(|p| {
+                Box::pin(async move { get_chain_id(&p).await.ok() })
+                    as std::pin::Pin<Box<dyn Future<Output = Option<u64>> + Send>>
+            })
+            .collect();
+
+        self.reconcile(Self::poll_all(futures).await)
+    }
+
+    pub async fn get_block_number(&self) -> Result<u64> {
+        let futures = self
+            .providers
+            .iter()
+            .cloned()
+            .map(|p| {
+                Box::pin(async move { get_latest_block_number(&p).await.ok() })
+                    as std::pin::Pin<Box<dyn Future<Output = Option<u64>> + Send>>
+            })
+            .collect();
+
+        self.reconcile(Self::poll_all(futures).await)
+    }
+
+    pub async fn get_gas_price(&self) -> Result<U256> {
+        let futures = self
+            .providers
+            .iter()
+            .cloned()
+            .map(|p| {
+                Box::pin(async move { get_gas_price(&p).await.ok() })
+                    as std::pin::Pin<Box<dyn Future<Output = Option<U256>> + Send>>
+            })
+            .collect();
+
+        self.reconcile(Self::poll_all(futures).await)
+    }
+
+    /// Polls every endpoint concurrently without letting one's failure
+    /// abort the rest -- unlike `try_join_all`, which fails the whole call
+    /// the instant a single endpoint errors, defeating the point of a
+    /// quorum read. A lagging/erroring endpoint is simply excluded from the
+    /// vote; only the endpoints that actually answered are returned.
+    async fn poll_all<T>(futures: Vec<std::pin::Pin<Box<dyn Future<Output = Option<T>> + Send>>>) -> Vec<T> {
+        futures::future::join_all(futures).await.into_iter().flatten().collect()
+    }
+
+    /// Groups `results` (one per endpoint that actually responded) by
+    /// equality and returns the most-agreed-on value, provided its support
+    /// meets the configured [`QuorumPolicy`] computed against that
+    /// responded count.
+    fn reconcile<T: PartialEq + Clone>(&self, results: Vec<T>) -> Result<T> {
+        let total = results.len();
+        let mut groups: Vec<(T, usize)> = Vec::new();
+
+        for result in results {
+            if let Some(group) = groups.iter_mut().find(|(value, _)| *value == result) {
+                group.1 += 1;
+            } else {
+                groups.push((result, 1));
+            }
+        }
+
+        groups.sort_by(|a, b| b.1.cmp(&a.1));
+        let (value, agreeing) = groups
+            .into_iter()
+            .next()
+            .ok_or_else(|| UtilsError::Ethereum("No endpoints responded".to_string()))?;
+
+        let required = self.policy.required(total);
+        if agreeing >= required {
+            Ok(value)
+        } else {
+            Err(UtilsError::Ethereum(format!(
+                "Quorum not reached: only {}/{} endpoints agreed (needed {})",
+                agreeing, total, required
+            )))
+        }
+    }
+}
+
+// --- Retry client ---
+
+/// Classifies an RPC failure as worth retrying or not, the same distinction
+/// rate-limit-aware clients in other libraries make: transient conditions
+/// (rate limiting, timeouts, connection resets, HTTP 5xx) are retried,
+/// everything else (bad request, revert) is surfaced immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RetryClass {
+    Transient,
+    Fatal,
+}
+
+/// Classifies an RPC error message as retryable (HTTP 429, JSON-RPC "rate
+/// limit"/-32005, timeouts, connection resets, HTTP 500/502/503/504) or
+/// fatal (everything else, e.g. bad request/revert).
+fn classify_rpc_error(message: &str) -> RetryClass {
+    let lower = message.to_lowercase();
+    if lower.contains("429")
+        || lower.contains("rate limit")
+        || lower.contains("-32005")
+        || lower.contains("timeout")
+        || lower.contains("timed out")
+        || lower.contains("connection reset")
+        || lower.contains("500")
+        || lower.contains("502")
+        || lower.contains("503")
+        || lower.contains("504")
+    {
+        RetryClass::Transient
+    } else {
+        RetryClass::Fatal
+    }
+}
+
+/// Narrower than [`classify_rpc_error`]: distinguishes the rate-limit
+/// flavor of transient failure (429/-32005) from everything else
+/// (timeouts, connection resets, 5xx), so [`with_retry`] can surface
+/// exhausted retries as `UtilsError::RateLimited` versus `UtilsError::Timeout`.
+fn is_rate_limit_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    lower.contains("429") || lower.contains("rate limit") || lower.contains("-32005")
+}
+
+/// Tuning for [`RetryClient`]: how many times to retry a transient failure,
+/// the backoff between attempts, and the [`Throttler`]/[`CircuitBreaker`]
+/// this client paces calls and trips through.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub throttle: Duration,
+    pub circuit_failure_threshold: usize,
+    pub circuit_reset: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: MAX_RETRIES + 1,
+            base_backoff_ms: backoff_initial_delay().as_millis() as u64,
+            max_backoff_ms: 30_000,
+            throttle: Duration::from_millis(100),
+            circuit_failure_threshold: 5,
+            circuit_reset: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Applies `config.max_rpc_retries` (from `MAX_RPC_RETRIES`) on top of
+    /// the defaults, so flaky public RPC endpoints can be tuned without a
+    /// code change.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            max_attempts: config.max_rpc_retries.map(|r| r + 1).unwrap_or_else(|| Self::default().max_attempts),
+            ..Self::default()
+        }
+    }
+}
+
+/// Wraps a provider with the existing [`Throttler`] (pacing between
+/// attempts) and [`CircuitBreaker`] (tripping after
+/// `circuit_failure_threshold` consecutive failed calls of any kind, so
+/// callers fail fast instead of hammering a failing endpoint), retrying only the [`RetryClass::Transient`] failures with
+/// exponential backoff plus jitter.
+pub struct RetryClient {
+    provider: Arc<ReqwestProvider>,
+    config: RetryConfig,
+    throttler: AsyncMutex<Throttler>,
+    breaker: AsyncMutex<CircuitBreaker>,
+}
+
+impl RetryClient {
+    pub fn new(provider: Arc<ReqwestProvider>, config: RetryConfig) -> Self {
+        Self {
+            throttler: AsyncMutex::new(Throttler::new(config.throttle)),
+            breaker: AsyncMutex::new(CircuitBreaker::new(config.circuit_failure_threshold, config.circuit_reset)),
+            provider,
+            config,
+        }
+    }
+
+    pub async fn get_chain_id(&self) -> Result<u64> {
+        self.call_with_retry("eth_chainId", |provider| async move { get_chain_id(provider).await }).await
+    }
+
+    pub async fn get_block_number(&self) -> Result<u64> {
+        self.call_with_retry("eth_blockNumber", |provider| async move { get_latest_block_number(provider).await }).await
+    }
+
+    pub async fn get_gas_price(&self) -> Result<U256> {
+        self.call_with_retry("eth_gasPrice", |provider| async move { get_gas_price(provider).await }).await
+    }
+
+    pub async fn get_balance(&self, address: Address) -> Result<U256> {
+        self.call_with_retry("eth_getBalance", |provider| async move {
+            provider.get_balance(address).await.map_err(map_eth_err)
+        }).await
+    }
+
+    pub async fn get_transaction_count(&self, address: Address) -> Result<u64> {
+        self.call_with_retry("eth_getTransactionCount", |provider| async move {
+            provider.get_transaction_count(address).await.map_err(map_eth_err)
+        }).await
+    }
+
+    /// Probes the endpoint with the same retry/backoff policy as every
+    /// other call; equivalent to `check_provider_health`, but resilient to
+    /// a single transient hiccup instead of surfacing it immediately.
+    pub async fn check_health(&self) -> Result<()> {
+        self.get_block_number().await.map(|_| ())
+    }
+
+    /// Connected peer count via `net_peerCount`.
+    pub async fn get_peer_count(&self) -> Result<u64> {
+        self.call_with_retry("net_peerCount", |provider| async move {
+            let raw: String = provider.client().request("net_peerCount", serde_json::json!([])).await.map_err(map_eth_err)?;
+            parse_hex_u64(&raw)
+        }).await
+    }
+
+    /// Sync progress via `eth_syncing` (`false` when fully synced, or an
+    /// object with `currentBlock`/`highestBlock` while catching up).
+    pub async fn get_sync_state(&self) -> Result<SyncState> {
+        self.call_with_retry("eth_syncing", |provider| async move {
+            let raw: serde_json::Value = provider.client().request("eth_syncing", serde_json::json!([])).await.map_err(map_eth_err)?;
+            parse_sync_state(&raw)
+        }).await
+    }
+
+    /// Unix timestamp of the latest block's header.
+    pub async fn get_latest_block_timestamp(&self) -> Result<u64> {
+        self.call_with_retry("eth_getBlockByNumber", |provider| async move {
+            let block = provider
+                .get_block_by_number(alloy::eips::BlockNumberOrTag::Latest, false)
+                .await
+                .map_err(map_eth_err)?;
+            block.map(|b| b.header.timestamp).ok_or_else(|| UtilsError::network("latest block unavailable"))
+        }).await
+    }
+
+    /// Aggregates peer count, sync progress, chain ID cross-check, and
+    /// latest-block staleness into the single snapshot `network status`
+    /// renders -- the same at-a-glance picture full Ethereum clients surface.
+    pub async fn get_node_health(&self, expected_chain_id: u64) -> Result<NodeHealth> {
+        let chain_id = self.get_chain_id().await?;
+        let peer_count = self.get_peer_count().await?;
+        let sync_state = self.get_sync_state().await?;
+        let latest_block = self.get_block_number().await?;
+        let latest_block_timestamp = self.get_latest_block_timestamp().await?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(latest_block_timestamp);
+        let latest_block_age_secs = now.saturating_sub(latest_block_timestamp);
+
+        Ok(NodeHealth {
+            chain_id,
+            expected_chain_id,
+            peer_count,
+            sync_state,
+            latest_block,
+            latest_block_age_secs,
+        })
+    }
+
+    /// Runs `op` under `network_timeout_duration()`, retrying
+    /// [`RetryClass::Transient`] failures (rate limiting, timeouts) with
+    /// jittered exponential backoff starting at `backoff_initial_delay()`
+    /// up to `max_attempts` times; a [`RetryClass::Fatal`] error (e.g. a
+    /// JSON-RPC revert) is returned immediately. `method` only labels the
+    /// error once retries are exhausted.
+    pub async fn call_with_retry<T, F, Fut>(&self, method: &str, op: F) -> Result<T>
+    where
+        F: Fn(&ReqwestProvider) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut delay_ms = self.config.base_backoff_ms;
+        let mut last_err = UtilsError::network("RetryClient: no attempts were made");
+
+        for attempt in 0..self.config.max_attempts {
+            let attempt_result = {
+                let mut throttler = self.throttler.lock().await;
+                let mut breaker = self.breaker.lock().await;
+                let guarded = breaker.execute(op(&self.provider));
+                throttler.throttle(tokio::time::timeout(network_timeout_duration(), guarded)).await
+            };
+
+            let result: Result<T> = match attempt_result {
+                Ok(inner) => inner,
+                Err(_elapsed) => Err(UtilsError::timeout(network_timeout_error().to_string())),
+            };
+
+            match result {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let is_last_attempt = attempt + 1 == self.config.max_attempts;
+                    let class = classify_rpc_error(&e.to_string());
+                    last_err = e;
+
+                    if class == RetryClass::Fatal {
+                        return Err(last_err);
+                    }
+                    if is_last_attempt {
+                        return Err(UtilsError::network(max_retries_exceeded_error(&format!(
+                            "{} ({}): {}",
+                            method, self.config.max_attempts, last_err
+                        ))));
+                    }
+
+                    let jitter_ms = rand::thread_rng().gen_range(0..=delay_ms);
+                    sleep(Duration::from_millis(delay_ms + jitter_ms)).await;
+                    delay_ms = (delay_ms * 2).min(self.config.max_backoff_ms);
+                }
+            }
+        }
+
+        Err(last_err)
+    }
+}
+
+// --- Generic retry with overall deadline ---
+
+/// Tuning for [`with_retry`]. Distinct from [`RetryConfig`] (which also
+/// wires up the [`Throttler`]/[`CircuitBreaker`] pair [`RetryClient`] needs
+/// for a long-lived provider): this is the bare backoff/timeout/deadline
+/// knobs for wrapping a single one-off `Future<Output = Result<T>>`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Timeout applied to each individual attempt.
+    pub per_attempt_timeout: Duration,
+    /// Wall-clock budget for the whole call, across every attempt; aborts
+    /// early once elapsed rather than letting a slow, repeatedly-failing
+    /// endpoint block indefinitely.
+    pub deadline: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: MAX_RETRIES + 1,
+            base_delay: backoff_initial_delay(),
+            max_delay: Duration::from_secs(30),
+            per_attempt_timeout: network_timeout_duration(),
+            deadline: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Runs `make_future` (called fresh for every attempt, since a `Future` can
+/// only be polled to completion once) under `policy`: each attempt is
+/// capped at `per_attempt_timeout`, failures [`classify_rpc_error`] deems
+/// [`RetryClass::Transient`] (connection reset, timeout, HTTP 429/5xx) are
+/// retried with jittered exponential backoff up to `max_attempts`, and the
+/// whole call aborts once `policy.deadline` has elapsed since the first
+/// attempt. A [`RetryClass::Fatal`] error is returned immediately. Once
+/// retries are exhausted, the result is `UtilsError::RateLimited` if the
+/// last failure was a 429/-32005, or `UtilsError::Timeout` otherwise.
+pub async fn with_retry<T, F, Fut>(policy: RetryPolicy, mut make_future: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let start = tokio::time::Instant::now();
+    let mut delay = policy.base_delay;
+    let mut last_err = UtilsError::network("with_retry: no attempts were made");
+
+    for attempt in 0..policy.max_attempts {
+        let elapsed = start.elapsed();
+        if elapsed >= policy.deadline {
+            return Err(UtilsError::timeout(format!(
+                "overall deadline of {:?} elapsed after {} attempt(s): {}",
+                policy.deadline, attempt, last_err
+            )));
+        }
+
+        let attempt_timeout = policy.per_attempt_timeout.min(policy.deadline - elapsed);
+
+        let result: Result<T> = match tokio::time::timeout(attempt_timeout, make_future()).await {
+            Ok(inner) => inner,
+            Err(_elapsed) => Err(UtilsError::timeout(format!(
+                "attempt {} timed out after {:?}",
+                attempt + 1,
+                attempt_timeout
+            ))),
+        };
+
+        match result {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                let is_last_attempt = attempt + 1 == policy.max_attempts;
+                let class = classify_rpc_error(&e.to_string());
+                let was_rate_limited = is_rate_limit_error(&e.to_string());
+                last_err = e;
+
+                if class == RetryClass::Fatal {
+                    return Err(last_err);
+                }
+                if is_last_attempt {
+                    let message = format!(
+                        "{} ({} attempts): {}",
+                        MAX_RETRIES_EXCEEDED_MSG, policy.max_attempts, last_err
+                    );
+                    return Err(if was_rate_limited {
+                        UtilsError::rate_limited(message)
+                    } else {
+                        UtilsError::timeout(message)
+                    });
+                }
+
+                let jitter_ms = rand::thread_rng().gen_range(0..=delay.as_millis() as u64);
+                sleep(delay + Duration::from_millis(jitter_ms)).await;
+                delay = (delay * 2).min(policy.max_delay);
+            }
+        }
+    }
+
+    Err(last_err)
+}
+
+// --- Node health (`network status`) ---
+
+/// Age a node's latest block must exceed before [`NodeHealth::is_healthy`]
+/// reports it as stalled, public so callers can color the same threshold.
+pub const STALE_BLOCK_AGE_SECS: u64 = 120;
+
+/// A node's sync progress as reported by `eth_syncing`.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SyncState {
+    Synced,
+    Syncing { current_block: u64, highest_block: u64, percent: f64 },
+}
+
+/// Connectivity/health snapshot for `network status`: peer count, sync
+/// progress, chain ID cross-checked against what's configured, and the
+/// latest block's age (a stalled node keeps serving its last block forever,
+/// so age -- not just the block number -- is what actually flags that).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NodeHealth {
+    pub chain_id: u64,
+    pub expected_chain_id: u64,
+    pub peer_count: u64,
+    pub sync_state: SyncState,
+    pub latest_block: u64,
+    pub latest_block_age_secs: u64,
+}
+
+impl NodeHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.chain_id == self.expected_chain_id
+            && self.peer_count > 0
+            && self.sync_state == SyncState::Synced
+            && self.latest_block_age_secs <= STALE_BLOCK_AGE_SECS
+    }
+}
+
+fn parse_hex_u64(value: &str) -> Result<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).map_err(|e| UtilsError::parse(e.to_string()))
+}
+
+fn parse_sync_state(raw: &serde_json::Value) -> Result<SyncState> {
+    match raw {
+        serde_json::Value::Object(obj) => {
+            let field = |name: &str| -> Result<u64> {
+                obj.get(name)
+                    .and_then(|v| v.as_str())
+                    .map(parse_hex_u64)
+                    .unwrap_or_else(|| Err(UtilsError::parse(format!("eth_syncing: missing `{}`", name))))
+            };
+            let current_block = field("currentBlock")?;
+            let highest_block = field("highestBlock")?;
+            let percent = if highest_block == 0 {
+                100.0
+            } else {
+                (current_block as f64 / highest_block as f64 * 100.0).min(100.0)
+            };
+            Ok(SyncState::Syncing { current_block, highest_block, percent })
+        }
+        _ => Ok(SyncState::Synced),
+    }
+}
+
+// --- EIP-1559 fee estimation ---
+
+/// Number of recent blocks sampled by `eth_feeHistory` when estimating fees.
+const FEE_HISTORY_BLOCK_COUNT: u64 = 10;
+/// Reward percentile requested from `eth_feeHistory` for the priority fee.
+const FEE_HISTORY_REWARD_PERCENTILE: f64 = 50.0;
+/// Priority fee used when `eth_feeHistory` returns no reward samples (e.g.
+/// an idle chain with no recent paid priority fees).
+const MIN_PRIORITY_FEE_WEI: u64 = 1_000_000_000;
+
+/// Suggested EIP-1559 fee values for the next block.
+#[derive(Debug, Clone, Copy)]
+pub struct Eip1559Estimate {
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Estimates `max_fee_per_gas`/`max_priority_fee_per_gas` from
+/// `eth_feeHistory`: the latest base fee plus the median of the sampled
+/// blocks' reward at [`FEE_HISTORY_REWARD_PERCENTILE`], with headroom for up
+/// to five consecutive max (12.5%) base-fee increases. Falls back to the legacy
+/// `eth_gasPrice` (as `max_fee_per_gas`, with no priority fee) on chains
+/// that don't support `eth_feeHistory`.
+pub async fn estimate_eip1559_fees(provider: &ReqwestProvider) -> Result<Eip1559Estimate> {
+    let history = match provider
+        .get_fee_history(
+            FEE_HISTORY_BLOCK_COUNT,
+            alloy::eips::BlockNumberOrTag::Latest,
+            &[FEE_HISTORY_REWARD_PERCENTILE],
+        )
+        .await
+    {
+        Ok(history) => history,
+        Err(e) => {
+            tracing::warn!(
+                "eth_feeHistory unavailable ({}); falling back to legacy gas price for EIP-1559 estimate",
+                e
+            );
+            let legacy = get_gas_price(provider).await?;
+            return Ok(Eip1559Estimate {
+                max_fee_per_gas: legacy,
+                max_priority_fee_per_gas: U256::ZERO,
+            });
+        }
+    };
+
+    let base_fee = history.base_fee_per_gas.last().copied().map(U256::from).unwrap_or(U256::ZERO);
+
+    let mut rewards: Vec<u128> = history
+        .reward
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|block_rewards| block_rewards.first().copied())
+        .collect();
+
+    let priority_fee = if rewards.is_empty() {
+        U256::from(MIN_PRIORITY_FEE_WEI)
     } else {
-        Ok(0)
+        rewards.sort_unstable();
+        U256::from(rewards[rewards.len() / 2])
+    };
+
+    Ok(Eip1559Estimate {
+        max_fee_per_gas: base_fee * U256::from(2u64) + priority_fee,
+        max_priority_fee_per_gas: priority_fee,
+    })
+}
+
+/// Connects to `network`'s primary RPC endpoint and wraps it in a
+/// [`RetryClient`] so reads retry rate-limit/timeout failures with backoff
+/// instead of surfacing them immediately.
+pub fn create_provider_with_retry(network: &NetworkConfig, config: RetryConfig) -> Result<RetryClient> {
+    let provider = create_provider(network)?;
+    Ok(RetryClient::new(provider, config))
+}
+
+// --- Node client detection & capability gating ---
+
+/// The node implementation behind an RPC endpoint, parsed from the leading
+/// token of `web3_clientVersion` (e.g. `"Geth/v1.13.0/..."` -> `Geth`).
+/// Mirrors the `NodeClient` distinction ethers-rs-style libraries use to
+/// avoid assuming every endpoint supports the same non-standard namespaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClient {
+    Geth,
+    Erigon,
+    /// Covers both OpenEthereum (Parity's successor) and Nethermind, which
+    /// report as `"OpenEthereum"`/`"Nethermind"` respectively but share the
+    /// same capability profile for our purposes.
+    Nethermind,
+    Besu,
+    /// Reported a `web3_clientVersion` we don't recognize.
+    Unknown,
+}
+
+impl NodeClient {
+    fn from_client_version(version: &str) -> Self {
+        let token = version.split('/').next().unwrap_or(version).to_lowercase();
+        match token.as_str() {
+            "geth" => NodeClient::Geth,
+            "erigon" => NodeClient::Erigon,
+            "nethermind" | "openethereum" | "parity-ethereum" | "parity" => NodeClient::Nethermind,
+            "besu" => NodeClient::Besu,
+            _ => NodeClient::Unknown,
+        }
+    }
+}
+
+/// A feature that isn't uniformly supported across node implementations,
+/// used to gate a code path before making the RPC call rather than
+/// surfacing whatever opaque error the node returns for a method it
+/// doesn't recognize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// `trace_*` namespace (Erigon, OpenEthereum/Nethermind; not Geth).
+    TraceNamespace,
+    /// `debug_*` namespace (Geth, Erigon, Besu; not OpenEthereum/Nethermind).
+    DebugNamespace,
+    /// `eth_feeHistory`, used by [`estimate_eip1559_fees`].
+    FeeHistory,
+    /// Historical state at an arbitrary block, used by
+    /// [`get_contract_creation_block`]'s binary search. Requires an archive
+    /// node regardless of client, so this only rules out clients with no
+    /// archive mode at all.
+    ArchiveState,
+}
+
+impl NodeClient {
+    /// Whether this client is known to support `capability`. `Unknown`
+    /// clients are assumed to support everything standard
+    /// (`FeeHistory`/`ArchiveState`) and nothing non-standard, so callers
+    /// fail closed only on the namespaces most likely to be missing.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match (self, capability) {
+            (_, Capability::FeeHistory) => true,
+            (_, Capability::ArchiveState) => true,
+            (NodeClient::Geth, Capability::DebugNamespace) => true,
+            (NodeClient::Erigon, Capability::DebugNamespace) => true,
+            (NodeClient::Besu, Capability::DebugNamespace) => true,
+            (NodeClient::Erigon, Capability::TraceNamespace) => true,
+            (NodeClient::Nethermind, Capability::TraceNamespace) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Calls `web3_clientVersion` and parses the result into a [`NodeClient`].
+pub async fn detect_node_client(provider: &ReqwestProvider) -> Result<NodeClient> {
+    let version = provider.client_version().await.map_err(map_eth_err)?;
+    Ok(NodeClient::from_client_version(&version))
+}
+
+/// Wraps a provider with a one-time [`NodeClient`] detection, so repeated
+/// capability checks (e.g. before every trace/debug call) don't re-query
+/// `web3_clientVersion`.
+pub struct CapabilityProvider {
+    provider: Arc<ReqwestProvider>,
+    client: OnceCell<NodeClient>,
+}
+
+impl CapabilityProvider {
+    pub fn new(provider: Arc<ReqwestProvider>) -> Self {
+        Self { provider, client: OnceCell::new() }
+    }
+
+    /// Returns the detected node client, querying `web3_clientVersion` on
+    /// first call and reusing the cached result afterward.
+    pub async fn client(&self) -> Result<NodeClient> {
+        self.client
+            .get_or_try_init(|| detect_node_client(&self.provider))
+            .await
+            .copied()
+    }
+
+    /// Returns `Ok(())` when the detected node supports `capability`, or a
+    /// clear `UtilsError::Ethereum` ("unsupported by this node") otherwise
+    /// so callers can short-circuit before an opaque RPC failure.
+    pub async fn require_capability(&self, capability: Capability) -> Result<()> {
+        let client = self.client().await?;
+        if client.supports(capability) {
+            Ok(())
+        } else {
+            Err(UtilsError::Ethereum(format!(
+                "{:?} unsupported by this node ({:?})",
+                capability, client
+            )))
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,160 @@
+//! WebSocket subscription transport for streaming `newHeads` and log events.
+//!
+//! `create_provider`/[`crate::network::ProviderPool`] only talk to an HTTP
+//! endpoint, which can only be polled. When `NetworkConfig::ws_rpc_url` is
+//! set, [`LogSubscriber`] opens a persistent WebSocket connection instead,
+//! decodes `Transfer` logs as they arrive into [`TransferEvent`], and
+//! reconnects with the same `INITIAL_DELAY_MS`-doubling backoff
+//! [`crate::network::ProviderPool`] uses for HTTP failover whenever the
+//! connection drops.
+
+use crate::config::NetworkConfig;
+use crate::error::{Result, UtilsError};
+use alloy::primitives::{Address, B256, U256};
+use alloy::providers::{Provider, ProviderBuilder, WsConnect};
+use alloy::rpc::types::eth::{Filter, Log};
+use ethereum_boilerplate_shared::network::INITIAL_DELAY_MS;
+use ethereum_boilerplate_shared::types::{ERC20Transfer, NFTTransfer};
+use futures::StreamExt;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Maximum backoff between reconnect attempts, so a long outage doesn't
+/// grow the delay unbounded.
+const MAX_RECONNECT_DELAY_MS: u64 = 60_000;
+
+/// A decoded ERC-20 or ERC-721 `Transfer(address,address,uint256)` log,
+/// distinguished by whether the third argument arrived as an indexed topic
+/// (NFT token ID) or as unindexed data (ERC-20 value).
+#[derive(Debug, Clone)]
+pub enum TransferEvent {
+    Erc20(ERC20Transfer),
+    Nft(NFTTransfer),
+}
+
+/// Streams decoded transfer events over a WebSocket connection, reconnecting
+/// on drop.
+pub struct LogSubscriber {
+    ws_url: String,
+}
+
+impl LogSubscriber {
+    pub fn new(network: &NetworkConfig) -> Result<Self> {
+        let ws_url = network.ws_rpc_url.clone().ok_or_else(|| {
+            UtilsError::config_error(format!(
+                "Network '{}' has no ws_rpc_url configured for subscriptions",
+                network.name
+            ))
+        })?;
+        Ok(Self { ws_url })
+    }
+
+    /// Subscribes to `Transfer` logs from `address` (or every address when
+    /// `None`), returning a channel of decoded events. A background task
+    /// drives the subscription and transparently reconnects with backoff;
+    /// it exits once the receiving end is dropped.
+    pub fn subscribe_logs(&self, address: Option<Address>) -> mpsc::UnboundedReceiver<TransferEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let ws_url = self.ws_url.clone();
+
+        tokio::spawn(async move {
+            let mut delay_ms = INITIAL_DELAY_MS;
+
+            loop {
+                match run_subscription(&ws_url, address, &tx).await {
+                    Ok(()) => break, // receiver dropped; nothing left to deliver to
+                    Err(e) => {
+                        if tx.is_closed() {
+                            break; // receiver dropped while disconnected
+                        }
+                        tracing::warn!(
+                            "log subscription to '{}' dropped ({}); reconnecting in {}ms",
+                            ws_url, e, delay_ms
+                        );
+                        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                        delay_ms = (delay_ms * 2).min(MAX_RECONNECT_DELAY_MS);
+                    }
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+async fn run_subscription(
+    ws_url: &str,
+    address: Option<Address>,
+    tx: &mpsc::UnboundedSender<TransferEvent>,
+) -> Result<()> {
+    let provider = ProviderBuilder::new()
+        .on_ws(WsConnect::new(ws_url))
+        .await
+        .map_err(UtilsError::network)?;
+
+    let mut filter = Filter::new().event_signature(transfer_topic0());
+    if let Some(address) = address {
+        filter = filter.address(address);
+    }
+
+    let subscription = provider.subscribe_logs(&filter).await.map_err(UtilsError::network)?;
+    let mut stream = subscription.into_stream();
+
+    while let Some(log) = stream.next().await {
+        if let Some(event) = decode_transfer(&log) {
+            if tx.send(event).is_err() {
+                // Receiver dropped; stop reconnecting.
+                return Ok(());
+            }
+        }
+    }
+
+    Err(UtilsError::network("WebSocket subscription stream ended"))
+}
+
+/// `keccak256("Transfer(address,address,uint256)")`, shared by ERC-20 and
+/// ERC-721 transfer events.
+fn transfer_topic0() -> B256 {
+    B256::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        .expect("transfer event signature is valid hex")
+}
+
+/// Decodes a raw `Transfer` log into an [`TransferEvent`]. A fourth indexed
+/// topic means the value is an NFT token ID; otherwise it's an ERC-20
+/// amount carried in the unindexed data.
+fn decode_transfer(log: &Log) -> Option<TransferEvent> {
+    let topics = log.topics();
+    if topics.first() != Some(&transfer_topic0()) {
+        return None;
+    }
+
+    let from = Address::from_word(*topics.get(1)?);
+    let to = Address::from_word(*topics.get(2)?);
+    let token_address = log.address();
+    let transaction_hash = log.transaction_hash.unwrap_or_default();
+    let block_number = log.block_number.unwrap_or_default();
+
+    if let Some(token_id_topic) = topics.get(3) {
+        Some(TransferEvent::Nft(NFTTransfer {
+            token_address,
+            from,
+            to,
+            token_id: U256::from_be_bytes(token_id_topic.0),
+            transaction_hash,
+            block_number,
+            timestamp: None,
+        }))
+    } else {
+        let value = U256::from_be_slice(log.data().data.as_ref());
+        Some(TransferEvent::Erc20(ERC20Transfer {
+            token_address,
+            from,
+            to,
+            value,
+            transaction_hash,
+            block_number,
+            timestamp: None,
+        }))
+    }
+}
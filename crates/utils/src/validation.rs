@@ -1,6 +1,8 @@
 //! Input validation utilities for Ethereum data types
+use crate::crypto::CryptoError;
 use crate::error::{Result, UtilsError};
-use alloy::primitives::{Address, TxHash as H256};
+use alloy::primitives::{Address, TxHash as H256, U256};
+use alloy::signers::local::PrivateKeySigner;
 use std::str::FromStr;
 
 /// Internal function for validating basic hex format.
@@ -31,9 +33,58 @@ pub fn validate_address(address: &str) -> Result<Address> {
         .map_err(|e| UtilsError::InvalidAddress(format!("Invalid address checksum or format: {}", e)))
 }
 
-/// Validates Ethereum private key format (0x + 64 hex chars)
+/// Validates an address's format the same way [`validate_address`] does,
+/// then additionally rejects it if its casing doesn't match its EIP-55
+/// checksum. Inputs that are all-lowercase or all-uppercase are accepted
+/// without a checksum, since that's the conventional "no checksum given"
+/// form; anything mixed-case must match exactly, catching typo'd addresses
+/// that the plain hex/length check lets through.
+pub fn validate_address_checksummed(input: &str) -> Result<Address> {
+    let address = validate_address(input)?;
+
+    let body = &input[2..];
+    let is_all_lower = !body.chars().any(|c| c.is_ascii_uppercase());
+    let is_all_upper = !body.chars().any(|c| c.is_ascii_lowercase());
+    if is_all_lower || is_all_upper {
+        return Ok(address);
+    }
+
+    let checksummed = crate::crypto::to_checksum_address(address);
+    if checksummed != input {
+        return Err(UtilsError::InvalidAddress(format!(
+            "Address '{}' does not match its EIP-55 checksum (expected '{}')",
+            input, checksummed
+        )));
+    }
+
+    Ok(address)
+}
+
+/// Validates Ethereum private key format (0x + 64 hex chars), distinguishing
+/// "wasn't valid hex" from "hex decoded but the scalar is out of curve
+/// range" so callers can surface the actual problem instead of a generic
+/// format error.
 pub fn validate_private_key(private_key: &str) -> Result<()> {
-    validate_hex_format(private_key, 66, "Private key")
+    let hex_part = private_key.strip_prefix("0x").ok_or_else(|| {
+        CryptoError::InvalidSecretKey("private key must start with 0x".to_string())
+    })?;
+
+    let bytes = alloy::hex::decode(hex_part).map_err(|e| {
+        CryptoError::InvalidSecretKey(format!("private key is not valid hex: {}", e))
+    })?;
+
+    if bytes.len() != 32 {
+        return Err(CryptoError::InvalidBufferLength { expected: 32, actual: bytes.len() }.into());
+    }
+
+    // The hex decoded and is the right length, but is it a valid secp256k1
+    // scalar (nonzero, less than the curve order)? Parsing as a signer
+    // checks that in one step.
+    private_key
+        .parse::<PrivateKeySigner>()
+        .map_err(|e| CryptoError::KeyDerivationFailed(e.to_string()))?;
+
+    Ok(())
 }
 
 /// Validates transaction hash format (0x + 64 hex chars)
@@ -61,6 +112,27 @@ pub fn validate_amount(amount: &str) -> Result<()> {
     Ok(())
 }
 
+/// Parses a decimal amount string (e.g. an ETH or ERC-20 token amount) into
+/// its smallest-unit integer representation, avoiding the floating-point
+/// precision loss a naive `f64` conversion would introduce.
+///
+/// Thin wrapper over [`ethereum_boilerplate_shared::utils::parse_units`] --
+/// the canonical implementation lives there since `utils` already depends on
+/// `shared` -- trimming whitespace and mapping its error into a `UtilsError`.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<U256> {
+    ethereum_boilerplate_shared::utils::parse_units(amount.trim(), decimals)
+        .map_err(|e| UtilsError::invalid_amount(e.to_string()))
+}
+
+/// Formats a smallest-unit integer amount back into a decimal string with
+/// `decimals` fractional digits, trimming trailing zeros (the inverse of
+/// [`parse_units`]). Thin wrapper over
+/// [`ethereum_boilerplate_shared::utils::format_units`]; see that function
+/// for the exact-integer math.
+pub fn format_units(value: U256, decimals: u8) -> String {
+    ethereum_boilerplate_shared::utils::format_units(value, decimals)
+}
+
 /// Validates chain ID against a list of supported networks
 pub fn validate_chain_id(chain_id: u64) -> Result<()> {
     // 1: Mainnet, 10: Optimism, 137: Polygon, 42161: Arbitrum, 11155111: Sepolia, 31337: Anvil
@@ -100,4 +172,39 @@ mod tests {
         assert!(validate_amount("1.2.3").is_err());
         assert!(validate_amount("abc").is_err());
     }
+
+    #[test]
+    fn test_parse_units_eth() {
+        assert_eq!(parse_units("1", 18).unwrap(), U256::from(1_000_000_000_000_000_000u64));
+        assert_eq!(parse_units("1.5", 18).unwrap(), U256::from(1_500_000_000_000_000_000u64));
+        assert_eq!(parse_units("0.000001", 18).unwrap(), U256::from(1_000_000_000_000u64));
+        assert!(parse_units("1.2345678901234567890", 18).is_err());
+        assert!(parse_units("abc", 18).is_err());
+        assert!(parse_units(".", 18).is_err());
+    }
+
+    #[test]
+    fn test_format_units_round_trips_parse_units() {
+        let amount = parse_units("1.5", 18).unwrap();
+        assert_eq!(format_units(amount, 18), "1.5");
+        assert_eq!(format_units(U256::from(1_000_000u64), 6), "1");
+        assert_eq!(format_units(U256::ZERO, 18), "0");
+    }
+
+    #[test]
+    fn test_validate_address_checksummed() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert!(validate_address_checksummed(checksummed).is_ok());
+        assert!(validate_address_checksummed(&checksummed.to_lowercase()).is_ok());
+
+        let mut mistyped = checksummed.to_string();
+        let swap_at = mistyped.rfind(|c: char| c.is_ascii_alphabetic()).unwrap();
+        let swapped_char = if mistyped.as_bytes()[swap_at].is_ascii_uppercase() {
+            mistyped.as_bytes()[swap_at].to_ascii_lowercase()
+        } else {
+            mistyped.as_bytes()[swap_at].to_ascii_uppercase()
+        };
+        mistyped.replace_range(swap_at..swap_at + 1, &(swapped_char as char).to_string());
+        assert!(validate_address_checksummed(&mistyped).is_err());
+    }
 }
\ No newline at end of file
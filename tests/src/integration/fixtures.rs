@@ -0,0 +1,96 @@
+//! Spawns a local `anvil` (falling back to `ganache`) dev node so
+//! integration tests exercise a real chain instead of tolerating whatever
+//! status a missing `localhost:8545` node happens to produce.
+
+use std::net::TcpListener;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Published Foundry/Hardhat default test mnemonic. Pinned explicitly
+/// (rather than relying on `anvil`'s own default) so the funded account
+/// address below stays reproducible even if a future `anvil` version
+/// changes what it derives with no `--mnemonic` given.
+const DEV_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+/// The first account `anvil`/`ganache` derive from [`DEV_MNEMONIC`] at
+/// `m/44'/60'/0'/0/0`, funded with 10000 ETH at genesis.
+pub const FUNDED_ACCOUNT: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+pub const FUNDED_BALANCE_WEI: &str = "10000000000000000000000";
+
+/// A spawned dev-node child process. Kills (and reaps) the child on drop --
+/// including while unwinding from a panicked assertion -- so a failed test
+/// never leaks an `anvil`/`ganache` process.
+pub struct DevNode {
+    child: Child,
+    pub rpc_url: String,
+}
+
+impl DevNode {
+    /// Spawns a dev node on a free port with [`DEV_MNEMONIC`] and polls
+    /// `eth_blockNumber` until it answers before returning.
+    pub async fn spawn() -> Self {
+        let port = free_port();
+        let rpc_url = format!("http://127.0.0.1:{}", port);
+
+        let child = spawn_anvil(port)
+            .or_else(|| spawn_ganache(port))
+            .expect("Neither `anvil` nor `ganache` is on PATH; install Foundry (anvil) to run this test");
+
+        let node = Self { child, rpc_url };
+        node.wait_until_ready().await;
+        node
+    }
+
+    async fn wait_until_ready(&self) {
+        let client = reqwest::Client::new();
+        let body = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "eth_blockNumber", "params": [] });
+
+        for _ in 0..50 {
+            if let Ok(resp) = client.post(&self.rpc_url).json(&body).send().await {
+                if resp.status().is_success() {
+                    return;
+                }
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+
+        panic!("Dev node at {} never answered eth_blockNumber", self.rpc_url);
+    }
+}
+
+impl Drop for DevNode {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn spawn_anvil(port: u16) -> Option<Child> {
+    Command::new("anvil")
+        .args(["--port", &port.to_string(), "--mnemonic", DEV_MNEMONIC, "--silent"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()
+}
+
+fn spawn_ganache(port: u16) -> Option<Child> {
+    Command::new("ganache")
+        .args(["--port", &port.to_string(), "--mnemonic", DEV_MNEMONIC, "-e", "10000", "--quiet"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()
+}
+
+/// Binds an ephemeral port and immediately releases it, the same port-0
+/// trick `full_workflow.rs`'s `spawn_app` uses, to hand a real child
+/// process a port nothing else is listening on yet.
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .expect("failed to bind an ephemeral port")
+        .local_addr()
+        .unwrap()
+        .port()
+}
@@ -59,6 +59,9 @@ use alloy::primitives::utils::format_ether;
             ethereum_rpc_url: Url::parse(rpc_url_str).expect("Failed to parse URL"),
             moralis_api_key: Some("secret_key".into()),
             database_url: None,
+            retry: Default::default(),
+            network: None,
+            extra_networks: Vec::new(),
         };
 
         assert!(config.ethereum_rpc_url.as_str().contains("alchemy"));